@@ -0,0 +1,30 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use greek_number::{to_greek_lowercase, GreekNumberFormatter};
+
+fn small(c: &mut Criterion) {
+    // `to_greek_lowercase` takes the fast path below 10000, the formatter always the general one.
+    let formatter = GreekNumberFormatter::new();
+    c.bench_function("to_greek_lowercase 1..10000", |b| {
+        b.iter(|| {
+            (1..10_000)
+                .map(|n| to_greek_lowercase(black_box(n)).len())
+                .sum::<usize>()
+        })
+    });
+    c.bench_function("formatter 1..10000", |b| {
+        b.iter(|| {
+            (1..10_000)
+                .map(|n| formatter.format(black_box(n)).len())
+                .sum::<usize>()
+        })
+    });
+}
+
+fn large(c: &mut Criterion) {
+    c.bench_function("to_greek_lowercase 2_056_839_184", |b| {
+        b.iter(|| to_greek_lowercase(black_box(2_056_839_184)))
+    });
+}
+
+criterion_group!(benches, small, large);
+criterion_main!(benches);
@@ -0,0 +1,11 @@
+//! Stringify an arbitrary number and parse it back, with `cargo fuzz run round_trip`.
+
+#![no_main]
+
+use greek_number::{from_greek, GreekNumber};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|greek: GreekNumber| {
+    let numeral = greek.to_string();
+    assert_eq!(from_greek(&numeral), Ok(greek.value()), "{}", numeral);
+});
@@ -0,0 +1,107 @@
+use alloc::string::String;
+
+// Signs for one and five times each power of ten, from 1 up to 10000. Five and its multiples are written with the
+// pente `Π`; 50 to 50000 are the combined signs, a pente enclosing the sign for the power.
+const ONE_SIGNS: [char; 5] = ['Ι', 'Δ', 'Η', 'Χ', 'Μ'];
+const FIVE_SIGNS: [char; 5] = ['Π', '\u{10144}', '\u{10145}', '\u{10146}', '\u{10147}'];
+
+/// Stringify a number to Attic (acrophonic) Greek numbers
+///
+/// This is the older, additive system: `Ι` 1, `Π` 5, `Δ` 10, `𐅄` 50, `Η` 100, `𐅅` 500, `Χ` 1000, `𐅆` 5000,
+/// `Μ` 10000 and `𐅇` 50000. Each decimal digit is written as at most one five sign followed by up to four one signs,
+/// from the highest power down.
+///
+/// # Panics
+///
+/// Attic numerals have no zero and no sign above 50000, so this panics if `n` is 0 or above 99999. Use
+/// [`checked_to_attic`] to handle those numbers.
+///
+/// # Examples
+///
+/// ```
+/// use greek_number::to_attic;
+///
+/// let attic = to_attic(49);
+/// println!("{}", attic); // "ΔΔΔΔΠΙΙΙΙ"
+///
+/// let attic = to_attic(1982);
+/// println!("{}", attic); // "Χ𐅅ΗΗΗΗ𐅄ΔΔΔΙΙ"
+/// ```
+pub fn to_attic(n: usize) -> String {
+    checked_to_attic(n)
+        .unwrap_or_else(|| panic!("Attic numerals only reach from 1 to 99999, got {}", n))
+}
+
+/// Stringify a number to Attic (acrophonic) Greek numbers, returning `None` if it is out of range
+///
+/// This is [`to_attic`] for numbers that may be 0 or above 99999, which Attic numerals cannot write.
+///
+/// # Examples
+///
+/// ```
+/// use greek_number::checked_to_attic;
+///
+/// println!("{:?}", checked_to_attic(49)); // Some("ΔΔΔΔΠΙΙΙΙ")
+/// println!("{:?}", checked_to_attic(100_000)); // None
+/// ```
+pub fn checked_to_attic(n: usize) -> Option<String> {
+    if !(1..100_000).contains(&n) {
+        return None;
+    }
+
+    let mut attic = String::new();
+    for power in (0..5).rev() {
+        let mut digit = n / 10usize.pow(power as u32) % 10;
+        if digit >= 5 {
+            attic.push(FIVE_SIGNS[power]);
+            digit -= 5;
+        }
+        for _ in 0..digit {
+            attic.push(ONE_SIGNS[power]);
+        }
+    }
+    Some(attic)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{checked_to_attic, to_attic};
+
+    #[test]
+    fn to_attic_test() {
+        assert_eq!(to_attic(1), "Ι");
+        assert_eq!(to_attic(4), "ΙΙΙΙ");
+        assert_eq!(to_attic(5), "Π");
+        assert_eq!(to_attic(49), "ΔΔΔΔΠΙΙΙΙ");
+        assert_eq!(to_attic(50), "\u{10144}");
+        assert_eq!(to_attic(500), "\u{10145}");
+        assert_eq!(to_attic(1982), "Χ\u{10145}ΗΗΗΗ\u{10144}ΔΔΔΙΙ");
+        assert_eq!(to_attic(5000), "\u{10146}");
+        assert_eq!(
+            to_attic(99_999),
+            "\u{10147}ΜΜΜΜ\u{10146}ΧΧΧΧ\u{10145}ΗΗΗΗ\u{10144}ΔΔΔΔΠΙΙΙΙ"
+        );
+    }
+
+    #[test]
+    fn checked_to_attic_test() {
+        for n in [1, 49, 1982, 99_999] {
+            assert_eq!(checked_to_attic(n), Some(to_attic(n)));
+        }
+        assert_eq!(checked_to_attic(0), None);
+        assert_eq!(checked_to_attic(100_000), None);
+        assert_eq!(checked_to_attic(usize::MAX), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn to_attic_zero_test() {
+        to_attic(0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn to_attic_out_of_range_test() {
+        to_attic(100_000);
+    }
+}
@@ -0,0 +1,62 @@
+use core::fmt::{self, Write};
+
+/// Transliterate a Greek character to Beta Code, as used by the Thesaurus Linguae Graecae.
+///
+/// Capitals are marked with a leading `*`. Characters without a Beta Code are kept as they are.
+fn beta_code(c: char) -> Option<(bool, &'static str)> {
+    let (upper, code) = match c {
+        'α' | 'Α' => (c == 'Α', "A"),
+        'β' | 'Β' => (c == 'Β', "B"),
+        'γ' | 'Γ' => (c == 'Γ', "G"),
+        'δ' | 'Δ' => (c == 'Δ', "D"),
+        'ε' | 'Ε' => (c == 'Ε', "E"),
+        'ζ' | 'Ζ' => (c == 'Ζ', "Z"),
+        'η' | 'Η' => (c == 'Η', "H"),
+        'θ' | 'Θ' => (c == 'Θ', "Q"),
+        'ι' | 'Ι' => (c == 'Ι', "I"),
+        'κ' | 'Κ' => (c == 'Κ', "K"),
+        'λ' | 'Λ' => (c == 'Λ', "L"),
+        'μ' | 'Μ' => (c == 'Μ', "M"),
+        'ν' | 'Ν' => (c == 'Ν', "N"),
+        'ξ' | 'Ξ' => (c == 'Ξ', "C"),
+        'ο' | 'Ο' => (c == 'Ο', "O"),
+        'π' | 'Π' => (c == 'Π', "P"),
+        'ρ' | 'Ρ' => (c == 'Ρ', "R"),
+        'σ' | 'Σ' => (c == 'Σ', "S"),
+        'τ' | 'Τ' => (c == 'Τ', "T"),
+        'υ' | 'Υ' => (c == 'Υ', "U"),
+        'φ' | 'Φ' => (c == 'Φ', "F"),
+        'χ' | 'Χ' => (c == 'Χ', "X"),
+        'ψ' | 'Ψ' => (c == 'Ψ', "Y"),
+        'ω' | 'Ω' => (c == 'Ω', "W"),
+        'ϝ' | 'Ϝ' => (c == 'Ϝ', "V"),
+        'ϟ' | 'Ϟ' => (c == 'Ϟ', "#1"),
+        'ϛ' | 'Ϛ' => (c == 'Ϛ', "#2"),
+        'ϡ' | 'Ϡ' | 'ͳ' | 'Ͳ' => (c == 'Ϡ' || c == 'Ͳ', "#5"),
+        '\u{0374}' | '\u{02B9}' => (false, "#"),
+        '\u{0375}' => (false, "#22"),
+        _ => return None,
+    };
+    Some((upper, code))
+}
+
+/// A writer that transliterates everything written through it to Beta Code.
+pub(crate) struct BetaCodeWriter<'a, W: Write>(pub(crate) &'a mut W);
+
+impl<W: Write> Write for BetaCodeWriter<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        s.chars().try_for_each(|c| self.write_char(c))
+    }
+
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        match beta_code(c) {
+            Some((upper, code)) => {
+                if upper {
+                    self.0.write_char('*')?;
+                }
+                self.0.write_str(code)
+            }
+            None => self.0.write_char(c),
+        }
+    }
+}
@@ -0,0 +1,1877 @@
+use alloc::borrow::Cow;
+use alloc::string::String;
+use core::fmt::{self, Write};
+
+use crate::beta_code::BetaCodeWriter;
+use crate::groups::{digit_groups, groups, GreekGroup};
+use crate::html::{HtmlWriter, HTML_MAX_MARKUP_LEN, HTML_NUMBER_MARKUP_LEN};
+use crate::latex::{LatexWriter, MAX_MARKUP_LEN};
+use crate::tables::{
+    COPTIC_HUNDREDS, COPTIC_ONES, COPTIC_TENS, HUNDREDS, KERAIA, ONES, TENS, THOUSANDS_SIGN,
+};
+use crate::{Case, GreekNumberError, GREEK_MAX_DIGITS, GREEK_MAX_MYRIAD_POWER};
+
+/// How a group of letters is marked as a number
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum KeraiaStyle {
+    /// Append U+0374 GREEK NUMERAL SIGN, the code point Unicode recommends for the keraia.
+    #[default]
+    NumeralSign,
+    /// Append U+02B9 MODIFIER LETTER PRIME, which looks the same and is what many texts and keyboards produce.
+    ModifierPrime,
+    /// Draw a bar over the letters instead, as many manuscripts do: every digit letter is followed by U+0305
+    /// COMBINING OVERLINE, e.g. `σ̅μ̅α̅` for 241. The thousands sign `͵` and the myriad prefix are not overlined, and
+    /// [`OverlineStyle`] tells which groups are.
+    Overline,
+    /// No mark at all, e.g. `σμα` for 241, where the context already makes clear that it is a number. Groups with a
+    /// thousands sign look the same as with a keraia.
+    None,
+    /// A single U+0374 keraia at the very end of the whole numeral rather than one per group, as in running text where
+    /// the groups are read together, e.g. `αΜθ, ͵ζφνδʹ` for 97554. [`from_greek`](crate::from_greek) does not read
+    /// numbers of more than one group written this way.
+    GroupTrailingOnly,
+}
+
+/// Which myriad groups are overlined by [`KeraiaStyle::Overline`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OverlineStyle {
+    /// Overline the letters of every group, e.g. `αΜθ̅, ͵ζ̅φ̅ν̅δ̅` for 97554.
+    #[default]
+    EveryGroup,
+    /// Overline only the letters of the last group, e.g. `αΜθ, ͵ζ̅φ̅ν̅δ̅` for 97554, as some editions bar the numeral
+    /// once at its end. [`from_greek`](crate::from_greek) does not read numbers of more than one group written this
+    /// way.
+    LastGroupOnly,
+}
+
+/// U+0305 COMBINING OVERLINE
+const OVERLINE: char = '\u{0305}';
+
+impl KeraiaStyle {
+    /// The character appended after the letters, if any.
+    fn char(self) -> Option<char> {
+        match self {
+            KeraiaStyle::NumeralSign | KeraiaStyle::GroupTrailingOnly => Some('\u{0374}'),
+            KeraiaStyle::ModifierPrime => Some('\u{02B9}'),
+            KeraiaStyle::Overline | KeraiaStyle::None => None,
+        }
+    }
+}
+
+/// The letter used for the numeral 6
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SixStyle {
+    /// Stigma `ϛ`/`Ϛ` (U+03DB/U+03DA).
+    #[default]
+    Stigma,
+    /// The sigma-tau digraph `στ`/`ΣΤ`, common in print where fonts lack a stigma.
+    SigmaTau,
+    /// Digamma `ϝ`/`Ϝ` (U+03DD/U+03DC), the letter the stigma descends from.
+    Digamma,
+}
+
+impl SixStyle {
+    fn glyphs(self) -> [&'static str; 2] {
+        match self {
+            SixStyle::Stigma => ["ϛ", "Ϛ"],
+            SixStyle::SigmaTau => ["στ", "ΣΤ"],
+            SixStyle::Digamma => ["ϝ", "Ϝ"],
+        }
+    }
+}
+
+/// The letter used for the numeral 900
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SampiStyle {
+    /// Sampi `ϡ`/`Ϡ` (U+03E1/U+03E0).
+    #[default]
+    Sampi,
+    /// Archaic sampi `ͳ`/`Ͳ` (U+0373/U+0372), the form found in inscriptions.
+    Archaic,
+}
+
+impl SampiStyle {
+    fn glyphs(self) -> [&'static str; 2] {
+        match self {
+            SampiStyle::Sampi => ["ϡ", "Ϡ"],
+            SampiStyle::Archaic => ["ͳ", "Ͳ"],
+        }
+    }
+}
+
+/// How zero is written
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ZeroStyle {
+    /// The Greek Zero Sign `𐆊` (U+1018A).
+    #[default]
+    Sign,
+    /// An omicron with a macron, `ο̄`/`Ο̄`, the letter form of zero in Greek astronomical tables.
+    Omicron,
+    /// The digit `0`.
+    Digit,
+}
+
+/// Named separators between myriad groups, for [`GreekNumberFormatter::separator`]
+///
+/// Each is a punctuation mark followed by a space, and [`from_greek`](crate::from_greek) reads all of them back.
+///
+/// # Examples
+///
+/// ```
+/// use greek_number::{GreekNumberFormatter, Separator};
+///
+/// let formatter = GreekNumberFormatter::new().separator(Separator::AnoTeleia);
+/// println!("{}", formatter.format(97_554)); // "αΜθʹ\u{0387} ͵ζφνδ"
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Separator {
+    /// A comma, `", "`, the default.
+    #[default]
+    Comma,
+    /// The Greek ano teleia U+0387, `"· "`. It canonically decomposes to the middle dot, so normalized output has
+    /// [`Separator::MiddleDot`] instead.
+    AnoTeleia,
+    /// The middle dot U+00B7, `"· "`.
+    MiddleDot,
+}
+
+impl Separator {
+    /// The string written between groups.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Separator::Comma => ", ",
+            Separator::AnoTeleia => "\u{0387} ",
+            Separator::MiddleDot => "\u{00B7} ",
+        }
+    }
+}
+
+impl From<Separator> for Cow<'static, str> {
+    fn from(separator: Separator) -> Self {
+        Cow::Borrowed(separator.as_str())
+    }
+}
+
+/// Where the thousands sign `͵` goes in a group with a thousands digit
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ThousandsStyle {
+    /// Right before the thousands letter, after the myriad prefix, e.g. `αΜ͵εχπγ` for 5683 × 10000.
+    #[default]
+    BeforeDigit,
+    /// A single sign before all the thousands letters of the group, as some typographic styles place it, e.g. `͵εχπγ`
+    /// for 5683. A group has a single thousands digit, since ten thousands are already a myriad, so this writes
+    /// exactly what [`ThousandsStyle::BeforeDigit`] does.
+    GroupPrefix,
+    /// A single sign at the start of the whole group, before the myriad prefix, e.g. `͵αΜεχπγ` for 5683 × 10000.
+    /// Groups without a myriad prefix look the same as with [`ThousandsStyle::BeforeDigit`].
+    BeforeGroup,
+    /// No thousands sign: the thousands letter is marked by an upper keraia U+0374 in front of it instead, e.g. `ʹβ`
+    /// for 2000 and `αΜθʹ, ʹζφνδ` for 97554.
+    ///
+    /// The lower left keraia `͵` is the usual mark, see [Greek numerals](https://en.wikipedia.org/wiki/Greek_numerals)
+    /// and U+0375 GREEK LOWER NUMERAL SIGN in the [Unicode Greek chart](https://www.unicode.org/charts/PDF/U0370.pdf);
+    /// some editions and digital texts write the plain keraia before the letter in its place.
+    /// [`from_greek`](crate::from_greek) does not read it.
+    AlphaMark,
+}
+
+/// The alphabet the digit letters are taken from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Alphabet {
+    /// Greek letters.
+    #[default]
+    Greek,
+    /// Coptic letters (U+2C80 to U+2CFF, and fei U+03E4/U+03E5 for 90) in the same positional and myriad system, e.g.
+    /// `ⲥⲙⲁʹ` for 241, with the Coptic `Ⲙ` for the myriad sign. 6 is always sou `ⲋ` and 900 the Coptic sampi `ⳁ`, so
+    /// [`SixStyle`] and [`SampiStyle`] do not apply; neither does [`ZeroStyle::Omicron`], and
+    /// [`from_greek`](crate::from_greek) does not read Coptic.
+    Coptic,
+}
+
+/// What is written for numbers the Greek system has no good numeral for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FallbackStyle {
+    /// No fallback: zero is written as set by [`ZeroStyle`] and out of range numbers are an error.
+    #[default]
+    None,
+    /// The number in Latin digits, as is: `"0"` for zero and the decimal string for numbers of 10^40 and above, which
+    /// are then no longer an error. The case, keraia and script options do not apply to it.
+    Decimal,
+}
+
+/// What the infallible methods like [`GreekNumberFormatter::format`] do with a number they cannot write
+///
+/// Every integer they take is below the 10^40 ceiling of the numerals, so this is about numbers above the maximum set
+/// with [`GreekNumberFormatter::max_value`]. The `try_` methods always return the error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OverflowPolicy {
+    /// Panic with the error the `try_` methods return.
+    #[default]
+    Panic,
+    /// Write the maximum instead, e.g. `͵θϡϟθ` for 10000 with `max_value(9999)`.
+    Clamp,
+    /// Write the number in Latin digits instead, e.g. `10000` with `max_value(9999)`, after the negative sign if any.
+    /// The case, keraia and script options do not apply to it.
+    Decimal,
+}
+
+/// How the myriad power of a group is written
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MyriadStyle {
+    /// The power as a lowercase ones letter before `Μ`, e.g. `βΜκʹ` for 20 × 10000^2.
+    #[default]
+    Prefix,
+    /// One dotted myriad sign `Μ̇` (`Μ` with U+0307 COMBINING DOT ABOVE) per power, in the manner of Diophantus'
+    /// "myriad of myriads", e.g. `Μ̇Μ̇κʹ` for 20 × 10000^2.
+    Dotted,
+    /// The power as a lowercase ones letter over `Μ`, as some editions print it. Unicode has no combining Greek
+    /// letters, so the letter follows `Μ` and both carry a U+0305 COMBINING OVERLINE, which joins them under one bar,
+    /// e.g. `Μ̅β̅κʹ` for 20 × 10000^2.
+    OverM,
+    /// A bare `Μ` without a power for the myriads, in the manner of texts that never go past a myriad of myriads, e.g.
+    /// `Μθʹ, ͵ζφνδ` for 97554. This only reaches up to 10^8 - 1: larger numbers are written as with
+    /// [`MyriadStyle::Prefix`]. [`from_greek`](crate::from_greek) does not read it.
+    FlatSingle,
+    /// One plain `Μ` per power, so that 10^8, the myriad of myriads, is a double myriad `ΜΜ`, e.g. `ΜΜαʹ` for
+    /// 100_000_000 and `ΜΜκʹ, Μ͵εχπγ, ͵θρπδ` for 2056839184. Editions stack the second `Μ` over the first; plain text
+    /// sets them side by side. Unlike [`MyriadStyle::Dotted`] the signs are undotted, so in uppercase they look like
+    /// the letter for 40, and [`from_greek`](crate::from_greek) does not read it.
+    DoubleMyriad,
+}
+
+/// The highest myriad power [`MyriadStyle::FlatSingle`] can write: a myriad of myriads already needs power 2.
+const FLAT_SINGLE_MAX_POWER: usize = 1;
+
+/// U+0307 COMBINING DOT ABOVE
+const DOT_ABOVE: char = '\u{0307}';
+
+/// U+2060 WORD JOINER
+const WORD_JOINER: char = '\u{2060}';
+
+/// The script the numeral is written in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Script {
+    /// Greek letters.
+    #[default]
+    Greek,
+    /// ASCII [Beta Code](https://en.wikipedia.org/wiki/Beta_Code) transliteration, e.g. `SMA#` for `σμαʹ` and `*S*M*A#`
+    /// for `ΣΜΑʹ`, with `#22` for the thousands sign `͵`. The myriad and keraia structure is kept as is.
+    BetaCode,
+    /// Greek letters wrapped in `\textgreek{...}` for LaTeX, with the `textalpha` macros `\textnumeralsigngreek{}` for
+    /// the keraia and `\textnumeralsignlowergreek{}` for the thousands sign, e.g.
+    /// `\textgreek{σμα\textnumeralsigngreek{}}` for `σμαʹ`. Characters special to LaTeX in the separator are
+    /// escaped; combining overlines and dots are kept as Unicode for XeLaTeX and LuaLaTeX.
+    Latex,
+    /// Greek letters as HTML, wrapped in `<span class="greek-number">` with each myriad group in
+    /// `<span class="greek-group">`, the keraia in `<span class="greek-keraia">` and the thousands sign in
+    /// `<span class="greek-thousands">`, so that CSS can style them. These class names are stable. The separator is
+    /// written as is, between the group spans.
+    Html,
+}
+
+/// The Unicode normalization form the numeral is written in
+///
+/// Note that the keraia U+0374 GREEK NUMERAL SIGN canonically decomposes to U+02B9 MODIFIER LETTER PRIME, so both NFC
+/// and NFD write every keraia as U+02B9.
+#[cfg(feature = "unicode-normalization")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Normalization {
+    /// The characters exactly as the other options produce them.
+    #[default]
+    AsEmitted,
+    /// Normalization Form C, canonical composition.
+    Nfc,
+    /// Normalization Form D, canonical decomposition.
+    Nfd,
+}
+
+/// Check that `n` has a single digit M power representation, i.e. that it is below 10^40.
+fn check_range(n: u128) -> Result<(), GreekNumberError> {
+    match n.checked_ilog10() {
+        Some(exponent) if exponent >= GREEK_MAX_DIGITS => Err(GreekNumberError::OutOfRange(n)),
+        _ => Ok(()),
+    }
+}
+
+/// Write each item with `write_item`, with `separator` between consecutive items and nowhere else.
+fn write_separated<W: Write, T>(
+    out: &mut W,
+    items: impl IntoIterator<Item = T>,
+    separator: &str,
+    mut write_item: impl FnMut(&mut W, T) -> fmt::Result,
+) -> fmt::Result {
+    for (i, item) in items.into_iter().enumerate() {
+        if i > 0 {
+            out.write_str(separator)?;
+        }
+        write_item(out, item)?;
+    }
+    Ok(())
+}
+
+/// A writer that only counts the chars written to it.
+struct CharCounter(usize);
+
+impl Write for CharCounter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+}
+
+/// Stringify numbers to Greek numbers with a chosen set of options
+///
+/// Start from [`GreekNumberFormatter::new`], which gives the same output as
+/// [`to_greek_lowercase`](crate::to_greek_lowercase), and change only the options you need.
+///
+/// # Out of range numbers
+///
+/// The numerals reach up to 10^40 - 1, and every integer the formatter takes is below that: even `u128::MAX` is only
+/// about 3.4 × 10^38. A lower ceiling can be set with [`max_value`](Self::max_value), e.g. 9999 to keep to the
+/// classical numerals without myriads. The `try_` methods return [`GreekNumberError::AboveMax`] above it, and the
+/// infallible ones like [`format`](Self::format) panic, clamp or write the number in decimal as set by
+/// [`overflow`](Self::overflow).
+///
+/// # Examples
+///
+/// ```
+/// use greek_number::{Case, GreekNumberFormatter, KeraiaStyle, SixStyle};
+///
+/// let formatter = GreekNumberFormatter::new()
+///     .case(Case::Upper)
+///     .keraia(KeraiaStyle::ModifierPrime);
+/// println!("{}", formatter.format(241)); // "ΣΜΑ\u{02B9}"
+///
+/// let formatter = GreekNumberFormatter::new()
+///     .six(SixStyle::SigmaTau)
+///     .separator(" ");
+/// println!("{}", formatter.format(60_006)); // "αΜστʹ στʹ"
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GreekNumberFormatter {
+    case: Case,
+    keraia: KeraiaStyle,
+    overline: OverlineStyle,
+    word_joiner: bool,
+    six: SixStyle,
+    sampi: SampiStyle,
+    script: Script,
+    alphabet: Alphabet,
+    separator: Cow<'static, str>,
+    leading_separator: bool,
+    grouping: Cow<'static, str>,
+    zero: ZeroStyle,
+    zero_keraia: bool,
+    myriad: MyriadStyle,
+    thousands: ThousandsStyle,
+    negative_sign: Cow<'static, str>,
+    fallback: FallbackStyle,
+    max: Option<u128>,
+    overflow: OverflowPolicy,
+    #[cfg(feature = "unicode-normalization")]
+    normalization: Normalization,
+}
+
+impl Default for GreekNumberFormatter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GreekNumberFormatter {
+    /// Create a formatter with the default options: lowercase, with the U+0374 keraia, stigma for 6, `", "` between
+    /// myriad groups, prefixed myriad powers, a bare zero sign for zero and `"-"` before negative numbers.
+    pub fn new() -> Self {
+        GreekNumberFormatter {
+            case: Case::Lower,
+            keraia: KeraiaStyle::NumeralSign,
+            overline: OverlineStyle::EveryGroup,
+            word_joiner: false,
+            six: SixStyle::Stigma,
+            sampi: SampiStyle::Sampi,
+            script: Script::Greek,
+            alphabet: Alphabet::Greek,
+            separator: Cow::Borrowed(", "),
+            leading_separator: false,
+            grouping: Cow::Borrowed(""),
+            zero: ZeroStyle::Sign,
+            zero_keraia: false,
+            myriad: MyriadStyle::Prefix,
+            thousands: ThousandsStyle::BeforeDigit,
+            negative_sign: Cow::Borrowed("-"),
+            fallback: FallbackStyle::None,
+            max: None,
+            overflow: OverflowPolicy::Panic,
+            #[cfg(feature = "unicode-normalization")]
+            normalization: Normalization::AsEmitted,
+        }
+    }
+
+    /// Set the case of the letters.
+    pub fn case(mut self, case: Case) -> Self {
+        self.case = case;
+        self
+    }
+
+    /// Set how groups are marked as numbers: the keraia character, an overline or nothing.
+    pub fn keraia(mut self, keraia: KeraiaStyle) -> Self {
+        self.keraia = keraia;
+        self
+    }
+
+    /// Set which groups are overlined when they are marked by [`KeraiaStyle::Overline`]. Every group by default.
+    pub fn overline(mut self, overline: OverlineStyle) -> Self {
+        self.overline = overline;
+        self
+    }
+
+    /// Set whether a U+2060 WORD JOINER is written before every keraia character. Off by default.
+    ///
+    /// The joiner is invisible but ties the keraia to the letters, so text processors that split words do not take
+    /// it for an apostrophe or a quote mark, e.g. at the end of a sentence. [`from_greek`](crate::from_greek) does not
+    /// read it.
+    pub fn word_joiner(mut self, word_joiner: bool) -> Self {
+        self.word_joiner = word_joiner;
+        self
+    }
+
+    /// Set the letter used for the numeral 6, in the ones, the thousands and the myriad prefix.
+    pub fn six(mut self, six: SixStyle) -> Self {
+        self.six = six;
+        self
+    }
+
+    /// Set the letter used for the numeral 900.
+    pub fn sampi(mut self, sampi: SampiStyle) -> Self {
+        self.sampi = sampi;
+        self
+    }
+
+    /// Set the script the numeral is written in.
+    pub fn script(mut self, script: Script) -> Self {
+        self.script = script;
+        self
+    }
+
+    /// Set the alphabet the digit letters are taken from.
+    pub fn alphabet(mut self, alphabet: Alphabet) -> Self {
+        self.alphabet = alphabet;
+        self
+    }
+
+    /// Set the string written between myriad groups, e.g. `","` for a compact form without the space, or one of the
+    /// [`Separator`] presets.
+    ///
+    /// With an empty separator every group but the last ends with a keraia, also a group with thousands, which
+    /// otherwise has none: `αΜ͵ϛʹψμεʹ` for 60000745, where `αΜ͵ϛψμεʹ` would read as 67450000. Without a keraia
+    /// character, as with [`KeraiaStyle::None`] and [`KeraiaStyle::Overline`], the groups still run into each other.
+    pub fn separator(mut self, separator: impl Into<Cow<'static, str>>) -> Self {
+        self.separator = separator.into();
+        self
+    }
+
+    /// Set whether the separator is also written before the first myriad group, e.g. a bullet before every group.
+    /// Off by default. Zero has no groups, so it never gets one.
+    pub fn leading_separator(mut self, leading_separator: bool) -> Self {
+        self.leading_separator = leading_separator;
+        self
+    }
+
+    /// Set the string written between the digit letters of a myriad group, e.g. `"\u{2009}"`, a thin space, to tell
+    /// the thousands, hundreds, tens and ones apart when teaching. Empty, and so off, by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use greek_number::GreekNumberFormatter;
+    ///
+    /// let formatter = GreekNumberFormatter::new().grouping("\u{2009}");
+    /// println!("{}", formatter.format(5683)); // "͵ε χ π γ"
+    /// ```
+    pub fn grouping(mut self, grouping: impl Into<Cow<'static, str>>) -> Self {
+        self.grouping = grouping.into();
+        self
+    }
+
+    /// Set how zero is written.
+    pub fn zero(mut self, zero: ZeroStyle) -> Self {
+        self.zero = zero;
+        self
+    }
+
+    /// Set whether zero is followed by a keraia like every other number. Off by default.
+    pub fn zero_keraia(mut self, zero_keraia: bool) -> Self {
+        self.zero_keraia = zero_keraia;
+        self
+    }
+
+    /// Set how the myriad power of a group is written.
+    pub fn myriad(mut self, myriad: MyriadStyle) -> Self {
+        self.myriad = myriad;
+        self
+    }
+
+    /// Set where the thousands sign goes in a group with a myriad prefix.
+    pub fn thousands(mut self, thousands: ThousandsStyle) -> Self {
+        self.thousands = thousands;
+        self
+    }
+
+    /// Set the string written before negative numbers by [`format_signed`](Self::format_signed).
+    pub fn negative_sign(mut self, negative_sign: impl Into<Cow<'static, str>>) -> Self {
+        self.negative_sign = negative_sign.into();
+        self
+    }
+
+    /// Set what is written for zero and for out of range numbers, instead of the zero sign and an error.
+    pub fn fallback(mut self, fallback: FallbackStyle) -> Self {
+        self.fallback = fallback;
+        self
+    }
+
+    /// Set the largest number that may be written, below the 10^40 - 1 the numerals reach; larger ones are an error.
+    ///
+    /// With `max_value(9999)` the formatter keeps to the classical everyday numerals, which have no myriads. The
+    /// [`FallbackStyle::Decimal`] fallback writes numbers above the maximum in decimal instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use greek_number::{GreekNumberError, GreekNumberFormatter};
+    ///
+    /// let classical = GreekNumberFormatter::new().max_value(9999);
+    /// println!("{:?}", classical.try_format(9999)); // Ok("͵θϡϟθ")
+    /// println!("{:?}", classical.try_format(10_000)); // Err(AboveMax(10000, 9999))
+    /// ```
+    pub fn max_value(mut self, max: u128) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Set what the infallible methods do with a number above the maximum. They panic by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use greek_number::{GreekNumberFormatter, OverflowPolicy};
+    ///
+    /// let classical = GreekNumberFormatter::new().max_value(9999);
+    /// println!("{}", classical.clone().overflow(OverflowPolicy::Clamp).format(10_000)); // "͵θϡϟθ"
+    /// println!("{}", classical.overflow(OverflowPolicy::Decimal).format(10_000)); // "10000"
+    /// ```
+    pub fn overflow(mut self, overflow: OverflowPolicy) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    /// Set the Unicode normalization form of the output.
+    #[cfg(feature = "unicode-normalization")]
+    pub fn normalization(mut self, normalization: Normalization) -> Self {
+        self.normalization = normalization;
+        self
+    }
+
+    /// Write the letters of one digit, overlined if its group is.
+    fn write_digit<W: Write>(&self, out: &mut W, glyph: &str, overlined: bool) -> fmt::Result {
+        if !overlined {
+            return out.write_str(glyph);
+        }
+        glyph.chars().try_for_each(|c| {
+            out.write_char(c)?;
+            out.write_char(OVERLINE)
+        })
+    }
+
+    /// Write a keraia character, after a word joiner if one is asked for.
+    fn write_keraia<W: Write>(&self, out: &mut W, keraia: char) -> fmt::Result {
+        if self.word_joiner {
+            out.write_char(WORD_JOINER)?;
+        }
+        out.write_char(keraia)
+    }
+
+    fn write_zero<W: Write>(&self, out: &mut W) -> fmt::Result {
+        match self.zero {
+            ZeroStyle::Sign => out.write_char('𐆊')?, // Greek Zero Sign https://www.compart.com/en/unicode/U+1018A
+            ZeroStyle::Omicron => {
+                out.write_str(match self.case {
+                    Case::Lower => "ο",
+                    Case::Upper => "Ο",
+                })?;
+                out.write_char('\u{0304}')?;
+            }
+            ZeroStyle::Digit => out.write_char('0')?,
+        }
+        if self.zero_keraia {
+            match self.keraia {
+                KeraiaStyle::Overline => out.write_char(OVERLINE)?,
+                keraia => {
+                    if let Some(keraia) = keraia.char() {
+                        self.write_keraia(out, keraia)?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// The glyph of a single digit (1 to 9) in the ones position.
+    fn one(&self, digit: usize, case: usize) -> &'static str {
+        if self.alphabet == Alphabet::Coptic {
+            COPTIC_ONES[digit - 1][case]
+        } else if digit == 6 {
+            self.six.glyphs()[case]
+        } else {
+            ONES[digit - 1][case]
+        }
+    }
+
+    /// An upper bound of the number of bytes `n` is written with, so the output is allocated only once.
+    fn capacity(&self, n: u128) -> usize {
+        // a word joiner before each keraia
+        let joiner_bytes = if self.word_joiner {
+            WORD_JOINER.len_utf8()
+        } else {
+            0
+        };
+        // at most a four byte zero sign or a two char omicron, and a keraia
+        let mut bytes = if n == 0 { 6 + joiner_bytes } else { 0 };
+        let mut signs = 1;
+        let mut separator_bytes = if self.leading_separator {
+            self.separator.len()
+        } else {
+            0
+        };
+
+        // Apart from the zero and the separator, every char of a numeral is a two byte Greek character, or at most a
+        // three byte Coptic one.
+        let letter_bytes = match self.alphabet {
+            Alphabet::Greek => 2,
+            Alphabet::Coptic => 3,
+        };
+        let six_chars = self.six.glyphs()[0].chars().count();
+        let marked = |chars: usize| match self.keraia {
+            KeraiaStyle::Overline => 2 * chars,
+            _ => chars,
+        };
+        for (i, group) in groups(n).enumerate() {
+            if i > 0 {
+                separator_bytes += self.separator.len();
+            }
+            // at most three spaces between the four digits
+            separator_bytes += 3 * self.grouping.len();
+            // a thousands sign and a keraia
+            signs += 2;
+            let mut chars = match self.myriad {
+                // A bare `Μ` is shorter than a prefixed one, which `FlatSingle` falls back to.
+                MyriadStyle::Prefix | MyriadStyle::FlatSingle if group.power() > 0 => six_chars + 1,
+                MyriadStyle::Prefix | MyriadStyle::FlatSingle => 0,
+                MyriadStyle::Dotted => 2 * group.power(),
+                MyriadStyle::DoubleMyriad => group.power(),
+                MyriadStyle::OverM if group.power() > 0 => 2 * (six_chars + 1),
+                MyriadStyle::OverM => 0,
+            };
+            if group.thousands() != 0 {
+                chars += 1 + marked(six_chars);
+            }
+            chars += marked((group.hundreds() != 0) as usize);
+            chars += marked((group.tens() != 0) as usize);
+            if group.ones() != 0 {
+                chars += marked(six_chars);
+            }
+            // keraia
+            chars += 1;
+            bytes += letter_bytes * chars + joiner_bytes;
+        }
+        match self.script {
+            Script::Greek => bytes + separator_bytes,
+            // A two byte Greek character is at most three bytes of Beta Code, like `*#1` for `Ϟ`.
+            Script::BetaCode => bytes.div_ceil(2) * 3 + separator_bytes,
+            // `\textgreek{}` around the numeral, and markup for the signs and the separators
+            Script::Latex => {
+                "\\textgreek{}".len() + bytes + (signs + separator_bytes) * MAX_MARKUP_LEN
+            }
+            // a span around the numeral, and one around each group or sign
+            Script::Html => {
+                HTML_NUMBER_MARKUP_LEN + bytes + separator_bytes + signs * HTML_MAX_MARKUP_LEN
+            }
+        }
+    }
+
+    /// The number of `char`s `n` is written with, computed without building the `String`
+    pub fn char_len(&self, n: u128) -> Result<usize, GreekNumberError> {
+        self.check_range(n)?;
+        let mut counter = CharCounter(0);
+        self.write(n, &mut counter)
+            .expect("counting chars cannot fail");
+        Ok(counter.0)
+    }
+
+    /// The glyph of a single digit (1 to 9) in the hundreds position.
+    fn hundred(&self, digit: usize, case: usize) -> &'static str {
+        if self.alphabet == Alphabet::Coptic {
+            COPTIC_HUNDREDS[digit - 1][case]
+        } else if digit == 9 {
+            self.sampi.glyphs()[case]
+        } else {
+            HUNDREDS[digit - 1][case]
+        }
+    }
+
+    /// The glyph of a single digit (1 to 9) in the tens position.
+    fn ten(&self, digit: usize, case: usize) -> &'static str {
+        match self.alphabet {
+            Alphabet::Greek => TENS[digit - 1][case],
+            Alphabet::Coptic => COPTIC_TENS[digit - 1][case],
+        }
+    }
+
+    /// The sign for a myriad, 10000.
+    fn myriad_sign(&self) -> char {
+        match self.alphabet {
+            Alphabet::Greek => 'Μ',
+            Alphabet::Coptic => 'Ⲙ',
+        }
+    }
+
+    /// Stringify a number to Greek numbers
+    pub fn format(&self, n: usize) -> String {
+        let mut fmt = String::new();
+        self.format_into(n, &mut fmt);
+        fmt
+    }
+
+    /// Stringify a number to Greek numbers, returning an error if it is out of range
+    pub fn try_format(&self, n: u128) -> Result<String, GreekNumberError> {
+        let mut fmt = String::new();
+        self.try_format_into(n, &mut fmt)?;
+        Ok(fmt)
+    }
+
+    /// Stringify a signed number to Greek numbers, with the negative sign before negative numbers
+    pub fn format_signed(&self, n: i128) -> String {
+        let mut fmt = String::new();
+        if n < 0 {
+            fmt.push_str(&self.negative_sign);
+        }
+        self.format_u128_into(n.unsigned_abs(), &mut fmt);
+        fmt
+    }
+
+    /// Append a number as Greek numbers to `fmt`
+    pub fn format_into(&self, n: usize, fmt: &mut String) {
+        self.format_u128_into(n as u128, fmt)
+    }
+
+    /// Append a number as Greek numbers to `fmt`, handling a number that cannot be written as the overflow policy says.
+    fn format_u128_into(&self, n: u128, fmt: &mut String) {
+        let error = match self.try_format_into(n, fmt) {
+            Ok(()) => return,
+            Err(error) => error,
+        };
+        match (self.overflow, error) {
+            (OverflowPolicy::Clamp, GreekNumberError::AboveMax(_, max)) => self
+                .try_format_into(max, fmt)
+                .expect("the maximum is below the ceiling"),
+            (OverflowPolicy::Decimal, GreekNumberError::AboveMax(n, _)) => {
+                write!(fmt, "{}", n).expect("writing to a String cannot fail")
+            }
+            (_, error) => panic!("{}", error),
+        }
+    }
+
+    /// Append a number as Greek numbers to `fmt`, returning an error if it is out of range
+    ///
+    /// Nothing is appended if the number is out of range.
+    pub fn try_format_into(&self, n: u128, fmt: &mut String) -> Result<(), GreekNumberError> {
+        self.check_range(n)?;
+        fmt.reserve(self.capacity(n));
+        self.write(n, fmt).expect("writing to a String cannot fail");
+        Ok(())
+    }
+
+    /// Stringify a number to Greek numbers of at most `max_graphemes` grapheme clusters
+    ///
+    /// A numeral that is too long is cut after `max_graphemes - 1` grapheme clusters and ends with an ellipsis `…`.
+    /// Cutting between grapheme clusters keeps each letter together with its combining marks, such as the overlines
+    /// of [`KeraiaStyle::Overline`].
+    #[cfg(feature = "unicode-segmentation")]
+    pub fn format_truncated(&self, n: usize, max_graphemes: usize) -> String {
+        use unicode_segmentation::UnicodeSegmentation;
+
+        let mut greek = self.format(n);
+        if greek.graphemes(true).nth(max_graphemes).is_some() {
+            let (end, _) = greek
+                .grapheme_indices(true)
+                .nth(max_graphemes.saturating_sub(1))
+                .expect("the numeral has more than max_graphemes grapheme clusters");
+            greek.truncate(end);
+            if max_graphemes > 0 {
+                greek.push('…');
+            }
+        }
+        greek
+    }
+
+    /// The number of terminal columns a number is displayed in as Greek numbers
+    ///
+    /// Letters and signs take one column each and combining marks none, so an overlined numeral is as wide as the
+    /// plain one even though it has twice as many `char`s. Ambiguous-width characters, which Greek letters are in East
+    /// Asian text, count as narrow.
+    #[cfg(feature = "unicode-width")]
+    pub fn display_width(&self, n: usize) -> usize {
+        unicode_width::UnicodeWidthStr::width(self.format(n).as_str())
+    }
+
+    /// Check that `n` can be written, which every number can with the decimal fallback.
+    fn check_range(&self, n: u128) -> Result<(), GreekNumberError> {
+        match self.fallback {
+            FallbackStyle::None => self.check_numeral(n),
+            FallbackStyle::Decimal => Ok(()),
+        }
+    }
+
+    /// Check that `n` has a numeral and is not above the maximum set for the formatter.
+    fn check_numeral(&self, n: u128) -> Result<(), GreekNumberError> {
+        match self.max {
+            Some(max) if n > max => Err(GreekNumberError::AboveMax(n, max)),
+            _ => check_range(n),
+        }
+    }
+
+    /// Write a number as Greek numbers; the number must already be checked to be in range.
+    pub(crate) fn write<W: Write>(&self, n: u128, out: &mut W) -> fmt::Result {
+        if self.fallback == FallbackStyle::Decimal && (n == 0 || self.check_numeral(n).is_err()) {
+            return write!(out, "{}", n);
+        }
+        #[cfg(feature = "unicode-normalization")]
+        if self.normalization != Normalization::AsEmitted {
+            use unicode_normalization::UnicodeNormalization;
+
+            let mut emitted = String::new();
+            self.write_script(n, &mut emitted)?;
+            return match self.normalization {
+                Normalization::Nfc => emitted.nfc().try_for_each(|c| out.write_char(c)),
+                _ => emitted.nfd().try_for_each(|c| out.write_char(c)),
+            };
+        }
+        self.write_script(n, out)
+    }
+
+    fn write_script<W: Write>(&self, n: u128, out: &mut W) -> fmt::Result {
+        match self.script {
+            Script::Greek => self.write_numeral(n, out),
+            Script::BetaCode => self.write_numeral(n, &mut BetaCodeWriter(out)),
+            Script::Latex => {
+                out.write_str("\\textgreek{")?;
+                self.write_numeral(n, &mut LatexWriter(out))?;
+                out.write_char('}')
+            }
+            Script::Html => {
+                out.write_str(r#"<span class="greek-number">"#)?;
+                self.write_numeral(n, &mut HtmlWriter(out))?;
+                out.write_str("</span>")
+            }
+        }
+    }
+
+    fn write_numeral<W: Write>(&self, n: u128, out: &mut W) -> fmt::Result {
+        if n == 0 {
+            return self.write_zero(out);
+        }
+        self.write_groups(groups(n), out)
+    }
+
+    /// Write a number given by its decimal digits, most significant first and without leading zeros.
+    pub(crate) fn write_digits<W: Write>(&self, digits: &[u8], out: &mut W) -> fmt::Result {
+        if digits.is_empty() {
+            return self.write_zero(out);
+        }
+        self.write_groups(digit_groups(digits), out)
+    }
+
+    /// Write the numeral of a non-zero number from its myriad groups, highest power first.
+    #[allow(non_snake_case)]
+    pub(crate) fn write_groups<W: Write>(
+        &self,
+        groups: impl Iterator<Item = GreekGroup>,
+        out: &mut W,
+    ) -> fmt::Result {
+        let mut groups = groups.peekable();
+        let top_power = groups.peek().map_or(0, GreekGroup::power);
+        let case = match self.case {
+            Case::Lower => 0,
+            Case::Upper => 1,
+        };
+        // M are used to represent 10000, M_power = 2 means 10000^2 = 10000 0000
+        // The prefix of M is also made of Greek numerals but only be single digits, so it is 9 at max. This enables us
+        // to represent up to (10000)^(9 + 1) - 1 = 10^40 -1  (9,999,999,999,999,999,999,999,999,999,999,999,999,999)
+        let myriad = match self.myriad {
+            MyriadStyle::FlatSingle if top_power > FLAT_SINGLE_MAX_POWER => MyriadStyle::Prefix,
+            myriad => myriad,
+        };
+        let write_M = |out: &mut W, M_power: usize| -> fmt::Result {
+            match myriad {
+                MyriadStyle::Prefix if M_power > 0 => {
+                    // the prefix of M is a single digit lowercase
+                    out.write_str(self.one(M_power, 0))?;
+                    out.write_char(self.myriad_sign())
+                }
+                MyriadStyle::Prefix => Ok(()),
+                MyriadStyle::Dotted => (0..M_power).try_for_each(|_| {
+                    out.write_char(self.myriad_sign())?;
+                    out.write_char(DOT_ABOVE)
+                }),
+                MyriadStyle::DoubleMyriad => {
+                    (0..M_power).try_for_each(|_| out.write_char(self.myriad_sign()))
+                }
+                MyriadStyle::OverM if M_power > 0 => {
+                    out.write_char(self.myriad_sign())?;
+                    out.write_char(OVERLINE)?;
+                    self.one(M_power, 0).chars().try_for_each(|c| {
+                        out.write_char(c)?;
+                        out.write_char(OVERLINE)
+                    })
+                }
+                MyriadStyle::OverM => Ok(()),
+                MyriadStyle::FlatSingle if M_power > 0 => out.write_char(self.myriad_sign()),
+                MyriadStyle::FlatSingle => Ok(()),
+            }
+        };
+
+        if self.leading_separator {
+            out.write_str(&self.separator)?;
+        }
+        // All-zero groups are not in `groups`, so they get no separator either.
+        let per_group_keraia = self.keraia != KeraiaStyle::GroupTrailingOnly;
+        let html = self.script == Script::Html;
+        let groups = core::iter::from_fn(|| {
+            let group = groups.next()?;
+            Some((group, groups.peek().is_none()))
+        });
+        write_separated(out, groups, &self.separator, |out, (group, last)| {
+            let M_power = group.power();
+            let overlined = self.keraia == KeraiaStyle::Overline
+                && (self.overline == OverlineStyle::EveryGroup || last);
+            debug_assert!(M_power <= GREEK_MAX_MYRIAD_POWER);
+            if html {
+                out.write_str(r#"<span class="greek-group">"#)?;
+            }
+
+            // `th`ousan, `h`undred, `t`en and `o`ne
+            let (th, h, t, o) = (
+                group.thousands(),
+                group.hundreds(),
+                group.tens(),
+                group.ones(),
+            );
+
+            // thousands are the ones letters marked with the lower numeral sign
+            if th != 0 && self.thousands == ThousandsStyle::BeforeGroup {
+                out.write_char(THOUSANDS_SIGN)?;
+            }
+            write_M(out, M_power)?;
+            // the grouping goes between digits, so not before the first one
+            let mut spaced = false;
+            let mut space = |out: &mut W| {
+                if core::mem::replace(&mut spaced, true) {
+                    out.write_str(&self.grouping)
+                } else {
+                    Ok(())
+                }
+            };
+            if th != 0 {
+                space(out)?;
+                let thousand_digit = self.one(th, case);
+                match self.thousands {
+                    ThousandsStyle::BeforeDigit | ThousandsStyle::GroupPrefix => {
+                        out.write_char(THOUSANDS_SIGN)?
+                    }
+                    ThousandsStyle::AlphaMark => out.write_char(KERAIA)?,
+                    ThousandsStyle::BeforeGroup => {}
+                }
+                self.write_digit(out, thousand_digit, overlined)?;
+            }
+            if h != 0 {
+                space(out)?;
+                let hundred_digit = self.hundred(h, case);
+                self.write_digit(out, hundred_digit, overlined)?;
+            }
+            if t != 0 {
+                space(out)?;
+                let ten_digit = self.ten(t, case);
+                self.write_digit(out, ten_digit, overlined)?;
+            }
+            if o != 0 {
+                space(out)?;
+                let one_digit = self.one(o, case);
+                self.write_digit(out, one_digit, overlined)?;
+            }
+            // if we do not have thousan, we need to append the keraia at the end. Without a separator every group
+            // needs one to end it.
+            let ends_group = self.separator.is_empty() && !last;
+            if (th == 0 && per_group_keraia) || ends_group {
+                if let Some(keraia) = self.keraia.char() {
+                    self.write_keraia(out, keraia)?;
+                }
+            }
+            if html {
+                out.write_str("</span>")?;
+            }
+            Ok(())
+        })?;
+        if !per_group_keraia {
+            if let Some(keraia) = self.keraia.char() {
+                self.write_keraia(out, keraia)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        write_separated, Alphabet, FallbackStyle, GreekNumberFormatter, KeraiaStyle, MyriadStyle,
+        OverflowPolicy, OverlineStyle, SampiStyle, Script, Separator, SixStyle, ThousandsStyle,
+        ZeroStyle,
+    };
+    use alloc::format;
+    use alloc::string::String;
+    use alloc::vec::Vec;
+    use core::fmt::Write;
+
+    use crate::{from_greek, to_greek_lowercase, to_greek_uppercase, Case, GreekNumberError};
+
+    #[test]
+    fn keraia_code_point_test() {
+        assert_eq!(to_greek_lowercase(1).chars().last(), Some('\u{0374}'));
+
+        let formatter = GreekNumberFormatter::new();
+        assert_eq!(formatter.format(1), "α\u{0374}");
+
+        let formatter = formatter.keraia(KeraiaStyle::ModifierPrime);
+        assert_eq!(formatter.format(1), "α\u{02B9}");
+        assert_eq!(formatter.format(97_554), "αΜθ\u{02B9}, ͵ζφνδ");
+        assert_eq!(formatter.case(Case::Upper).format(241), "ΣΜΑ\u{02B9}");
+    }
+
+    #[test]
+    fn six_style_test() {
+        let cases = [
+            (SixStyle::Stigma, ["ϛʹ", "͵ϛ", "χξϛʹ", "ϛΜαʹ"]),
+            (SixStyle::SigmaTau, ["στʹ", "͵στ", "χξστʹ", "στΜαʹ"]),
+            (SixStyle::Digamma, ["ϝʹ", "͵ϝ", "χξϝʹ", "ϝΜαʹ"]),
+        ];
+        for (six, [six_string, six_thousand_string, six_six_six_string, six_myriad_string]) in cases
+        {
+            let formatter = GreekNumberFormatter::new().six(six);
+            assert_eq!(formatter.format(6), six_string);
+            assert_eq!(formatter.format(6000), six_thousand_string);
+            assert_eq!(formatter.format(666), six_six_six_string);
+            assert_eq!(
+                formatter.try_format(10u128.pow(24)).unwrap(),
+                six_myriad_string
+            );
+        }
+        let formatter = GreekNumberFormatter::new()
+            .six(SixStyle::SigmaTau)
+            .case(Case::Upper);
+        assert_eq!(formatter.format(666), "ΧΞΣΤʹ");
+    }
+
+    #[test]
+    fn beta_code_test() {
+        let formatter = GreekNumberFormatter::new().script(Script::BetaCode);
+        assert_eq!(formatter.format(241), "SMA#");
+        assert_eq!(formatter.format(6090), "#22#2#1");
+        assert_eq!(
+            formatter.format(2_056_839_184),
+            "B*MK#, A*M#22EXPG, #22QRPD"
+        );
+
+        let formatter = formatter.case(Case::Upper);
+        assert_eq!(formatter.format(241), "*S*M*A#");
+        assert_eq!(
+            formatter.format(2_056_839_184),
+            "B*M*K#, A*M#22*E*X*P*G, #22*Q*R*P*D"
+        );
+        assert_eq!(formatter.six(SixStyle::SigmaTau).format(906), "*#5*S*T#");
+    }
+
+    #[test]
+    fn default_formatter_test() {
+        for n in [0, 1, 241, 5683, 97_554, 2_056_839_184, usize::MAX] {
+            assert_eq!(GreekNumberFormatter::new().format(n), to_greek_lowercase(n));
+            assert_eq!(
+                GreekNumberFormatter::default().format(n),
+                to_greek_lowercase(n)
+            );
+            assert_eq!(
+                GreekNumberFormatter::new().case(Case::Upper).format(n),
+                to_greek_uppercase(n)
+            );
+        }
+    }
+
+    #[test]
+    fn combined_options_test() {
+        let formatter = GreekNumberFormatter::new()
+            .case(Case::Upper)
+            .keraia(KeraiaStyle::ModifierPrime)
+            .six(SixStyle::Digamma)
+            .separator(String::from(" "));
+        assert_eq!(formatter.format(60_006), "αΜϜ\u{02B9} Ϝ\u{02B9}");
+        assert_eq!(
+            formatter.script(Script::BetaCode).format(60_006),
+            "A*M*V# *V#"
+        );
+    }
+
+    #[test]
+    fn separator_test() {
+        let formatter = GreekNumberFormatter::new().separator("·");
+        assert_eq!(formatter.format(2_056_839_184), "βΜκʹ·αΜ͵εχπγ·͵θρπδ");
+        assert_eq!(
+            from_greek(&formatter.format(2_056_839_184)),
+            Ok(2_056_839_184)
+        );
+
+        let formatter = GreekNumberFormatter::new().separator(",");
+        assert_eq!(formatter.format(97_554), "αΜθʹ,͵ζφνδ");
+        assert_eq!(
+            formatter
+                .clone()
+                .keraia(KeraiaStyle::ModifierPrime)
+                .format(97_554),
+            "αΜθ\u{02B9},͵ζφνδ"
+        );
+        assert_eq!(from_greek(&formatter.format(97_554)), Ok(97_554));
+
+        let formatter = GreekNumberFormatter::new().separator("");
+        assert_eq!(formatter.format(2_056_839_184), "βΜκʹαΜ͵εχπγʹ͵θρπδ");
+        assert_eq!(
+            from_greek(&formatter.format(2_056_839_184)),
+            Ok(2_056_839_184)
+        );
+        // A ones letter right before the next myriad prefix.
+        let greek = formatter.format(215_2120_1419_3274);
+        assert_eq!(greek, "γΜσιεʹβΜ͵βρκʹαΜ͵αυιθʹ͵γσοδ");
+        assert_eq!(from_greek(&greek), Ok(215_2120_1419_3274));
+        // A group with thousands gets a keraia to end it, so it does not run into the next group.
+        let greek = formatter.format(60_000_745);
+        assert_eq!(greek, "αΜ͵ϛʹψμεʹ");
+        assert_eq!(from_greek(&greek), Ok(60_000_745));
+        assert_eq!(
+            formatter
+                .clone()
+                .keraia(KeraiaStyle::GroupTrailingOnly)
+                .format(60_000_745),
+            "αΜ͵ϛʹψμεʹ"
+        );
+        for n in (0..100_000).map(|i| i * 184_467_440_737_095) {
+            for case in [Case::Lower, Case::Upper] {
+                let greek = formatter.clone().case(case).format(n);
+                assert_eq!(from_greek(&greek), Ok(n), "{}", greek);
+            }
+        }
+    }
+
+    #[test]
+    fn write_separated_test() {
+        let write = |items: &[&str]| {
+            let mut out = String::new();
+            write_separated(&mut out, items, "·", |out, item| out.write_str(item)).unwrap();
+            out
+        };
+        assert_eq!(write(&[]), "");
+        assert_eq!(write(&["α"]), "α");
+        assert_eq!(write(&["α", "β", "γ"]), "α·β·γ");
+    }
+
+    #[test]
+    fn sparse_groups_separator_test() {
+        let formatter = GreekNumberFormatter::new().separator("·");
+        for (n, greek) in [
+            (10_000, "αΜαʹ"),
+            (90_000_001, "αΜ͵θ·αʹ"),
+            (100_000_000, "βΜαʹ"),
+            (100_000_001, "βΜαʹ·αʹ"),
+            (100_010_000, "βΜαʹ·αΜαʹ"),
+            (1_000_000_000_000, "γΜαʹ"),
+            (1_000_000_000_001, "γΜαʹ·αʹ"),
+            (1_000_000_010_000, "γΜαʹ·αΜαʹ"),
+            (1_000_100_000_001, "γΜαʹ·βΜαʹ·αʹ"),
+        ] {
+            assert_eq!(formatter.format(n), greek, "{}", n);
+            assert_eq!(from_greek(greek), Ok(n));
+        }
+    }
+
+    #[test]
+    fn zero_style_test() {
+        let formatter = GreekNumberFormatter::new();
+        assert_eq!(formatter.format(0), "\u{1018A}");
+        assert_eq!(
+            formatter.clone().zero_keraia(true).format(0),
+            "\u{1018A}\u{0374}"
+        );
+
+        let formatter = formatter.zero(ZeroStyle::Omicron);
+        assert_eq!(formatter.format(0), "\u{03BF}\u{0304}");
+        assert_eq!(
+            formatter.clone().case(Case::Upper).format(0),
+            "\u{039F}\u{0304}"
+        );
+        assert_eq!(
+            formatter.clone().zero_keraia(true).format(0),
+            "\u{03BF}\u{0304}\u{0374}"
+        );
+
+        let formatter = formatter.zero(ZeroStyle::Digit);
+        assert_eq!(formatter.format(0), "0");
+        assert_eq!(
+            formatter
+                .zero_keraia(true)
+                .keraia(KeraiaStyle::ModifierPrime)
+                .format(0),
+            "0\u{02B9}"
+        );
+
+        // Only zero is affected.
+        let formatter = GreekNumberFormatter::new()
+            .zero(ZeroStyle::Digit)
+            .zero_keraia(true);
+        assert_eq!(formatter.format(10_000), "αΜαʹ");
+    }
+
+    #[test]
+    fn coptic_test() {
+        let coptic = GreekNumberFormatter::new().alphabet(Alphabet::Coptic);
+        assert_eq!(coptic.format(1), "\u{2C81}ʹ");
+        assert_eq!(coptic.format(241), "ⲥⲙⲁʹ");
+        assert_eq!(coptic.format(666), "ⲭⲝⲋʹ");
+        assert_eq!(coptic.format(999), "ⳁϥⲑʹ");
+        assert_eq!(coptic.format(5683), "͵ⲉⲭⲡⲅ");
+        assert_eq!(coptic.format(97_554), "ⲁⲘⲑʹ, ͵ⲍⲫⲛⲇ");
+        assert_eq!(coptic.clone().case(Case::Upper).format(241), "ⲤⲘⲀʹ");
+        assert_eq!(
+            coptic.clone().case(Case::Upper).format(97_554),
+            "ⲁⲘⲐʹ, ͵ⲌⲪⲚⲆ"
+        );
+        // The Greek styles for 6 and 900 do not apply.
+        assert_eq!(
+            coptic
+                .clone()
+                .six(SixStyle::SigmaTau)
+                .sampi(SampiStyle::Archaic)
+                .format(906),
+            "ⳁⲋʹ"
+        );
+        assert_eq!(
+            coptic.clone().myriad(MyriadStyle::Dotted).format(20_000),
+            "Ⲙ\u{0307}ⲃʹ"
+        );
+        assert_eq!(coptic.format(0), "\u{1018A}");
+    }
+
+    #[cfg(feature = "unicode-width")]
+    #[test]
+    fn display_width_test() {
+        let formatter = GreekNumberFormatter::new();
+        assert_eq!(formatter.display_width(241), 4);
+        assert_eq!(formatter.display_width(97_554), 11);
+
+        let overlined = formatter.keraia(KeraiaStyle::Overline);
+        assert_eq!(overlined.format(241).chars().count(), 6);
+        assert_eq!(overlined.display_width(241), 3);
+        assert_eq!(overlined.format(97_554).chars().count(), 15);
+        assert_eq!(overlined.display_width(97_554), 10);
+        assert_eq!(overlined.display_width(0), 1);
+    }
+
+    #[cfg(feature = "unicode-segmentation")]
+    #[test]
+    fn format_truncated_test() {
+        let formatter = GreekNumberFormatter::new();
+        assert_eq!(formatter.format_truncated(241, 4), "σμαʹ");
+        assert_eq!(formatter.format_truncated(241, 10), "σμαʹ");
+        assert_eq!(formatter.format_truncated(241, 3), "σμ…");
+        assert_eq!(formatter.format_truncated(241, 1), "…");
+        assert_eq!(formatter.format_truncated(241, 0), "");
+
+        let overlined = formatter.keraia(KeraiaStyle::Overline);
+        let greek = overlined.format(97_554);
+        assert_eq!(greek, "αΜθ\u{0305}, ͵ζ\u{0305}φ\u{0305}ν\u{0305}δ\u{0305}");
+        assert_eq!(overlined.format_truncated(97_554, 10), greek);
+        for (max, truncated) in [
+            (9, "αΜθ\u{0305}, ͵ζ\u{0305}φ\u{0305}…"),
+            (7, "αΜθ\u{0305}, ͵…"),
+            (4, "αΜθ\u{0305}…"),
+            (3, "αΜ…"),
+        ] {
+            let cut = overlined.format_truncated(97_554, max);
+            assert_eq!(cut, truncated);
+            // The cut is never between a letter and its overline.
+            let kept = cut.strip_suffix('…').unwrap();
+            assert!(greek.starts_with(kept));
+            assert_ne!(greek[kept.len()..].chars().next(), Some('\u{0305}'));
+        }
+    }
+
+    #[test]
+    fn fallback_test() {
+        let formatter = GreekNumberFormatter::new().fallback(FallbackStyle::Decimal);
+        assert_eq!(formatter.format(0), "0");
+        assert_eq!(formatter.try_format(0), Ok(String::from("0")));
+        assert_eq!(formatter.char_len(0), Ok(1));
+        // The zero options and the script do not apply to the fallback.
+        let styled = formatter
+            .clone()
+            .zero(ZeroStyle::Omicron)
+            .zero_keraia(true)
+            .script(Script::Latex);
+        assert_eq!(styled.format(0), "0");
+        assert_eq!(formatter.format_signed(0), "0");
+
+        // Every other number is still Greek: even `u128::MAX` is below the 10^40 ceiling.
+        assert_eq!(formatter.format(241), "σμαʹ");
+        assert_eq!(formatter.format_signed(-241), "-σμαʹ");
+        assert_eq!(
+            formatter.try_format(u128::MAX),
+            GreekNumberFormatter::new().try_format(u128::MAX)
+        );
+    }
+
+    #[test]
+    fn thousands_style_test() {
+        let before_digit = GreekNumberFormatter::new().thousands(ThousandsStyle::BeforeDigit);
+        let before_group = GreekNumberFormatter::new().thousands(ThousandsStyle::BeforeGroup);
+        for (n, digit, group) in [
+            (5683, "͵εχπγ", "͵εχπγ"),
+            (9184, "͵θρπδ", "͵θρπδ"),
+            (56_830_000, "αΜ͵εχπγ", "͵αΜεχπγ"),
+            (91_840_000, "αΜ͵θρπδ", "͵αΜθρπδ"),
+            (
+                2_056_839_184,
+                "βΜκʹ, αΜ͵εχπγ, ͵θρπδ",
+                "βΜκʹ, ͵αΜεχπγ, ͵θρπδ",
+            ),
+        ] {
+            assert_eq!(before_digit.format(n), digit);
+            assert_eq!(before_group.format(n), group);
+            assert_eq!(from_greek(group), Ok(n));
+        }
+        // A single thousands letter per group, so a single sign.
+        assert_eq!(before_digit.format(5683).matches('͵').count(), 1);
+        let group_prefix = GreekNumberFormatter::new().thousands(ThousandsStyle::GroupPrefix);
+        assert_eq!(group_prefix.format(5683), "͵εχπγ");
+        assert_eq!(group_prefix.format(5683).matches('͵').count(), 1);
+        for n in [5683, 56_830_000, 2_056_839_184] {
+            assert_eq!(group_prefix.format(n), before_digit.format(n));
+        }
+        assert_eq!(
+            before_group.clone().case(Case::Upper).format(56_830_000),
+            "͵αΜΕΧΠΓ"
+        );
+
+        let alpha_mark = GreekNumberFormatter::new().thousands(ThousandsStyle::AlphaMark);
+        assert_eq!(alpha_mark.format(2000), "\u{0374}β");
+        assert_eq!(alpha_mark.format(9000), "\u{0374}θ");
+        assert_eq!(alpha_mark.format(9184), "\u{0374}θρπδ");
+        assert_eq!(alpha_mark.format(97_554), "αΜθ\u{0374}, \u{0374}ζφνδ");
+        assert_eq!(
+            alpha_mark.clone().case(Case::Upper).format(2000),
+            "\u{0374}Β"
+        );
+        assert_eq!(
+            before_group.myriad(MyriadStyle::Dotted).format(56_830_000),
+            "͵Μ\u{0307}εχπγ"
+        );
+    }
+
+    #[test]
+    fn overline_test() {
+        let formatter = GreekNumberFormatter::new().keraia(KeraiaStyle::Overline);
+        assert_eq!(formatter.format(241), "σ\u{0305}μ\u{0305}α\u{0305}");
+        assert_eq!(
+            formatter.format(5683),
+            "͵ε\u{0305}χ\u{0305}π\u{0305}γ\u{0305}"
+        );
+        assert_eq!(
+            formatter.format(97_554),
+            "αΜθ\u{0305}, ͵ζ\u{0305}φ\u{0305}ν\u{0305}δ\u{0305}"
+        );
+        for n in [1, 241, 5683, 97_554, 2_056_839_184] {
+            assert_eq!(from_greek(&formatter.format(n)), Ok(n));
+        }
+        assert_eq!(
+            formatter.clone().six(SixStyle::SigmaTau).format(6),
+            "σ\u{0305}τ\u{0305}"
+        );
+        assert_eq!(
+            formatter.clone().case(Case::Upper).format(241),
+            "Σ\u{0305}Μ\u{0305}Α\u{0305}"
+        );
+        assert_eq!(formatter.format(0), "\u{1018A}");
+        assert_eq!(formatter.zero_keraia(true).format(0), "\u{1018A}\u{0305}");
+    }
+
+    #[test]
+    fn myriad_style_test() {
+        let prefix = GreekNumberFormatter::new().myriad(MyriadStyle::Prefix);
+        assert_eq!(prefix.format(20_000), "αΜβʹ");
+        assert_eq!(prefix.format(2_056_839_184), "βΜκʹ, αΜ͵εχπγ, ͵θρπδ");
+
+        let dotted = GreekNumberFormatter::new().myriad(MyriadStyle::Dotted);
+        assert_eq!(dotted.format(20_000), "Μ\u{0307}βʹ");
+        assert_eq!(
+            dotted.format(2_056_839_184),
+            "Μ\u{0307}Μ\u{0307}κʹ, Μ\u{0307}͵εχπγ, ͵θρπδ"
+        );
+        assert_eq!(dotted.case(Case::Upper).format(20_000), "Μ\u{0307}Βʹ");
+
+        let double = GreekNumberFormatter::new().myriad(MyriadStyle::DoubleMyriad);
+        assert_eq!(double.format(100_000_000), "ΜΜαʹ");
+        assert_eq!(double.format(200_010_000), "ΜΜβʹ, Μαʹ");
+        assert_eq!(double.format(97_554), "Μθʹ, ͵ζφνδ");
+        assert_eq!(double.format(2_056_839_184), "ΜΜκʹ, Μ͵εχπγ, ͵θρπδ");
+        assert_eq!(double.format(1_000_000_000_000), "ΜΜΜαʹ");
+
+        let flat = GreekNumberFormatter::new().myriad(MyriadStyle::FlatSingle);
+        assert_eq!(flat.format(97_554), "Μθʹ, ͵ζφνδ");
+        assert_eq!(flat.format(90_000_001), "Μ͵θ, αʹ");
+        assert_eq!(flat.format(99_999_999), "Μ͵θϡϟθ, ͵θϡϟθ");
+        assert_eq!(flat.format(9999), "͵θϡϟθ");
+        assert_eq!(flat.clone().case(Case::Upper).format(97_554), "ΜΘʹ, ͵ΖΦΝΔ");
+        // From a myriad of myriads up, the powers are needed again.
+        assert_eq!(flat.format(100_000_000), prefix.format(100_000_000));
+        assert_eq!(flat.format(2_056_839_184), prefix.format(2_056_839_184));
+
+        let over_m = GreekNumberFormatter::new().myriad(MyriadStyle::OverM);
+        assert_eq!(over_m.format(20_000), "Μ\u{0305}α\u{0305}βʹ");
+        assert_eq!(
+            over_m.format(2_056_839_184),
+            "Μ\u{0305}β\u{0305}κʹ, Μ\u{0305}α\u{0305}͵εχπγ, ͵θρπδ"
+        );
+        // The combining overline directly follows every `Μ`.
+        let greek = over_m.try_format(u128::MAX).unwrap();
+        let chars: Vec<char> = greek.chars().collect();
+        assert!(chars.contains(&'Μ'));
+        for (i, &c) in chars.iter().enumerate() {
+            if c == 'Μ' {
+                assert_eq!(chars.get(i + 1), Some(&'\u{0305}'), "{}", greek);
+            }
+        }
+        assert_eq!(
+            over_m.case(Case::Upper).format(20_000),
+            "Μ\u{0305}α\u{0305}Βʹ"
+        );
+        assert_eq!(
+            GreekNumberFormatter::new()
+                .myriad(MyriadStyle::OverM)
+                .six(SixStyle::SigmaTau)
+                .try_format(10u128.pow(24))
+                .unwrap(),
+            "Μ\u{0305}σ\u{0305}τ\u{0305}αʹ"
+        );
+    }
+
+    #[test]
+    fn capacity_is_upper_bound_test() {
+        let formatters = [
+            GreekNumberFormatter::new(),
+            GreekNumberFormatter::new().case(Case::Upper),
+            GreekNumberFormatter::new().six(SixStyle::SigmaTau),
+            GreekNumberFormatter::new().keraia(KeraiaStyle::Overline),
+            GreekNumberFormatter::new().myriad(MyriadStyle::Dotted),
+            GreekNumberFormatter::new()
+                .myriad(MyriadStyle::OverM)
+                .six(SixStyle::SigmaTau),
+            GreekNumberFormatter::new().myriad(MyriadStyle::FlatSingle),
+            GreekNumberFormatter::new().myriad(MyriadStyle::DoubleMyriad),
+            GreekNumberFormatter::new().alphabet(Alphabet::Coptic),
+            GreekNumberFormatter::new()
+                .alphabet(Alphabet::Coptic)
+                .keraia(KeraiaStyle::Overline)
+                .script(Script::BetaCode),
+            GreekNumberFormatter::new().separator(" \u{00B7} "),
+            GreekNumberFormatter::new()
+                .separator("\\")
+                .leading_separator(true)
+                .script(Script::Latex),
+            GreekNumberFormatter::new()
+                .zero(ZeroStyle::Omicron)
+                .zero_keraia(true),
+            GreekNumberFormatter::new()
+                .word_joiner(true)
+                .zero_keraia(true),
+            GreekNumberFormatter::new()
+                .script(Script::BetaCode)
+                .case(Case::Upper),
+            GreekNumberFormatter::new()
+                .script(Script::Latex)
+                .separator("\\"),
+            GreekNumberFormatter::new()
+                .script(Script::Latex)
+                .zero_keraia(true),
+            GreekNumberFormatter::new()
+                .script(Script::Html)
+                .zero_keraia(true),
+            GreekNumberFormatter::new()
+                .script(Script::Html)
+                .thousands(ThousandsStyle::AlphaMark)
+                .leading_separator(true),
+            GreekNumberFormatter::new()
+                .script(Script::Html)
+                .keraia(KeraiaStyle::GroupTrailingOnly),
+        ];
+        let numbers = [0, 1, 6, 90, 666, 9999, 6666_6666, u128::MAX];
+        for formatter in &formatters {
+            for n in numbers {
+                let greek = formatter.try_format(n).unwrap();
+                assert!(
+                    greek.len() <= formatter.capacity(n),
+                    "{:?} {}",
+                    formatter,
+                    n
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn format_signed_test() {
+        let formatter = GreekNumberFormatter::new();
+        assert_eq!(formatter.format_signed(-241), "-σμαʹ");
+        assert_eq!(formatter.format_signed(0), "\u{1018A}");
+        assert_eq!(formatter.format_signed(241), "σμαʹ");
+        assert_eq!(
+            formatter.format_signed(i128::MIN),
+            format!(
+                "-{}",
+                formatter.try_format(i128::MIN.unsigned_abs()).unwrap()
+            )
+        );
+
+        let formatter = formatter.negative_sign("\u{2212}").case(Case::Upper);
+        assert_eq!(formatter.format_signed(-241), "\u{2212}ΣΜΑʹ");
+    }
+
+    #[test]
+    fn latex_test() {
+        let formatter = GreekNumberFormatter::new().script(Script::Latex);
+        assert_eq!(
+            formatter.format(241),
+            "\\textgreek{σμα\\textnumeralsigngreek{}}"
+        );
+        assert_eq!(
+            formatter.format(5683),
+            "\\textgreek{\\textnumeralsignlowergreek{}εχπγ}"
+        );
+        assert_eq!(
+            formatter.format(97_554),
+            "\\textgreek{αΜθ\\textnumeralsigngreek{}, \\textnumeralsignlowergreek{}ζφνδ}"
+        );
+        assert_eq!(
+            formatter.clone().separator("~").format(97_554),
+            "\\textgreek{αΜθ\\textnumeralsigngreek{}\\textasciitilde{}\\textnumeralsignlowergreek{}ζφνδ}"
+        );
+        assert_eq!(formatter.format(0), "\\textgreek{\u{1018A}}");
+    }
+
+    #[cfg(feature = "unicode-normalization")]
+    #[test]
+    fn normalization_test() {
+        use super::Normalization;
+
+        let formatter = GreekNumberFormatter::new().keraia(KeraiaStyle::Overline);
+        let overlined = "σ\u{0305}μ\u{0305}α\u{0305}";
+        assert_eq!(formatter.format(241), overlined);
+        assert_eq!(
+            formatter
+                .clone()
+                .normalization(Normalization::AsEmitted)
+                .format(241),
+            overlined
+        );
+        // No precomposed Greek letter has an overline, so both forms keep the combining marks.
+        assert_eq!(
+            formatter
+                .clone()
+                .normalization(Normalization::Nfc)
+                .format(241),
+            overlined
+        );
+        assert_eq!(
+            formatter
+                .clone()
+                .normalization(Normalization::Nfd)
+                .format(241),
+            overlined
+        );
+
+        // The numeral sign keraia decomposes to the modifier prime in either form.
+        let formatter = GreekNumberFormatter::new().keraia(KeraiaStyle::NumeralSign);
+        assert_eq!(
+            formatter
+                .clone()
+                .normalization(Normalization::Nfc)
+                .format(241),
+            "σμα\u{02B9}"
+        );
+        assert_eq!(
+            formatter.normalization(Normalization::Nfd).format(241),
+            "σμα\u{02B9}"
+        );
+
+        // The omicron zero is left alone; its macron has nothing to compose with.
+        let formatter = GreekNumberFormatter::new().zero(ZeroStyle::Omicron);
+        assert_eq!(
+            formatter.normalization(Normalization::Nfc).format(0),
+            "ο\u{0304}"
+        );
+    }
+
+    #[test]
+    fn char_len_test() {
+        let formatters = [
+            GreekNumberFormatter::new(),
+            GreekNumberFormatter::new().six(SixStyle::SigmaTau),
+            GreekNumberFormatter::new().keraia(KeraiaStyle::Overline),
+            GreekNumberFormatter::new().script(Script::Latex),
+        ];
+        for formatter in &formatters {
+            for n in [0, 1, 666, 97_554, u128::MAX] {
+                let greek = formatter.try_format(n).unwrap();
+                assert_eq!(formatter.char_len(n), Ok(greek.chars().count()));
+            }
+        }
+    }
+
+    #[test]
+    fn sampi_style_test() {
+        let formatter = GreekNumberFormatter::new();
+        assert_eq!(formatter.format(900), "ϡʹ");
+        assert_eq!(formatter.format(999), "ϡϟθʹ");
+
+        let formatter = formatter.sampi(SampiStyle::Archaic);
+        assert_eq!(formatter.format(900), "ͳʹ");
+        assert_eq!(formatter.format(999), "ͳϟθʹ");
+        // 9000 is the ones letter with the thousands sign, so only the hundreds change.
+        assert_eq!(formatter.format(9000), "͵θ");
+        assert_eq!(formatter.format(9999), "͵θͳϟθ");
+        assert_eq!(formatter.clone().case(Case::Upper).format(900), "Ͳʹ");
+        assert_eq!(from_greek(&formatter.format(9999)), Ok(9999));
+    }
+
+    #[test]
+    fn no_keraia_test() {
+        let with = GreekNumberFormatter::new();
+        let without = GreekNumberFormatter::new().keraia(KeraiaStyle::None);
+        let cases = [
+            (1, "αʹ", "α"),
+            (241, "σμαʹ", "σμα"),
+            (5683, "͵εχπγ", "͵εχπγ"),
+            (97_554, "αΜθʹ, ͵ζφνδ", "αΜθ, ͵ζφνδ"),
+            (2_000_000_000, "βΜκʹ", "βΜκ"),
+            (90_000_001, "αΜ͵θ, αʹ", "αΜ͵θ, α"),
+        ];
+        for (n, with_keraia, without_keraia) in cases {
+            assert_eq!(with.format(n), with_keraia);
+            assert_eq!(without.format(n), without_keraia);
+        }
+        assert_eq!(without.clone().case(Case::Upper).format(241), "ΣΜΑ");
+        assert_eq!(without.zero_keraia(true).format(0), "\u{1018A}");
+    }
+
+    #[test]
+    fn group_trailing_only_keraia_test() {
+        let formatter = GreekNumberFormatter::new().keraia(KeraiaStyle::GroupTrailingOnly);
+        let cases = [
+            (1, "αʹ"),
+            (241, "σμαʹ"),
+            (5683, "͵εχπγʹ"),
+            (97_554, "αΜθ, ͵ζφνδʹ"),
+            (90_000_001, "αΜ͵θ, αʹ"),
+            (2_056_839_184, "βΜκ, αΜ͵εχπγ, ͵θρπδʹ"),
+        ];
+        for (n, greek) in cases {
+            let formatted = formatter.format(n);
+            assert_eq!(formatted, greek);
+            assert_eq!(formatted.matches('\u{0374}').count(), 1);
+        }
+        // A single group still reads back.
+        assert_eq!(from_greek(&formatter.format(241)), Ok(241));
+        assert_eq!(from_greek(&formatter.format(5683)), Ok(5683));
+        assert_eq!(formatter.format(0), "\u{1018A}");
+        assert_eq!(formatter.zero_keraia(true).format(0), "\u{1018A}\u{0374}");
+    }
+
+    #[test]
+    fn word_joiner_test() {
+        let formatter = GreekNumberFormatter::new();
+        let joined = formatter.clone().word_joiner(true);
+        for n in [0, 1, 241, 1000, 97_554, 100_000_001] {
+            assert!(!formatter.format(n).contains('\u{2060}'), "{}", n);
+            assert_eq!(
+                joined.format(n).replace('\u{2060}', ""),
+                formatter.format(n)
+            );
+        }
+        assert_eq!(joined.format(241), "σμα\u{2060}\u{0374}");
+        assert_eq!(joined.format(1000), "͵α");
+        assert_eq!(joined.format(97_554), "αΜθ\u{2060}\u{0374}, ͵ζφνδ");
+        assert_eq!(
+            joined.clone().zero_keraia(true).format(0),
+            "\u{1018A}\u{2060}\u{0374}"
+        );
+        assert_eq!(joined.keraia(KeraiaStyle::None).format(241), "σμα");
+    }
+
+    #[test]
+    fn separator_presets_test() {
+        let format = |separator: Separator| {
+            GreekNumberFormatter::new()
+                .separator(separator)
+                .format(97_554)
+        };
+        assert_eq!(
+            format(Separator::Comma),
+            GreekNumberFormatter::new().format(97_554)
+        );
+        assert_eq!(format(Separator::AnoTeleia), "αΜθʹ\u{0387} ͵ζφνδ");
+        assert_eq!(format(Separator::MiddleDot), "αΜθʹ\u{00B7} ͵ζφνδ");
+        for separator in [Separator::Comma, Separator::AnoTeleia, Separator::MiddleDot] {
+            let formatter = GreekNumberFormatter::new().separator(separator);
+            for n in [97_554, 2_056_839_184, 100_000_001] {
+                assert_eq!(from_greek(&formatter.format(n)), Ok(n));
+            }
+        }
+    }
+
+    #[test]
+    fn max_value_test() {
+        let classical = GreekNumberFormatter::new().max_value(9999);
+        assert_eq!(classical.try_format(9999).as_deref(), Ok("͵θϡϟθ"));
+        assert_eq!(classical.try_format(0).as_deref(), Ok("\u{1018A}"));
+        assert_eq!(
+            classical.try_format(10_000),
+            Err(GreekNumberError::AboveMax(10_000, 9999))
+        );
+        assert_eq!(
+            classical.char_len(10_000),
+            Err(GreekNumberError::AboveMax(10_000, 9999))
+        );
+        assert_eq!(classical.format(9999), "͵θϡϟθ");
+
+        let mut greek = String::from("x");
+        assert!(classical.try_format_into(10_000, &mut greek).is_err());
+        assert_eq!(greek, "x");
+
+        let fallback = classical.fallback(FallbackStyle::Decimal);
+        assert_eq!(fallback.format(9999), "͵θϡϟθ");
+        assert_eq!(fallback.format(10_000), "10000");
+    }
+
+    #[test]
+    #[should_panic(expected = "10000 is above the maximum of 9999")]
+    fn max_value_panics_test() {
+        GreekNumberFormatter::new().max_value(9999).format(10_000);
+    }
+
+    #[test]
+    fn overflow_policy_test() {
+        let classical = GreekNumberFormatter::new().max_value(9999);
+        let clamp = classical.clone().overflow(OverflowPolicy::Clamp);
+        assert_eq!(clamp.format(10_000), "͵θϡϟθ");
+        assert_eq!(clamp.format(241), "σμαʹ");
+        assert_eq!(clamp.format_signed(-10_000), "-͵θϡϟθ");
+        assert_eq!(
+            clamp.try_format(10_000),
+            Err(GreekNumberError::AboveMax(10_000, 9999))
+        );
+
+        let decimal = classical.overflow(OverflowPolicy::Decimal);
+        assert_eq!(decimal.format(10_000), "10000");
+        assert_eq!(decimal.format(9999), "͵θϡϟθ");
+        assert_eq!(
+            decimal.format_signed(i128::MIN),
+            format!("-{}", i128::MIN.unsigned_abs())
+        );
+        let mut greek = String::from("σμαʹ, ");
+        decimal.format_into(usize::MAX, &mut greek);
+        assert_eq!(greek, format!("σμαʹ, {}", usize::MAX));
+
+        // the largest u128 is clamped down to the maximum as well
+        let clamp = GreekNumberFormatter::new()
+            .max_value(10u128.pow(36))
+            .overflow(OverflowPolicy::Clamp);
+        assert_eq!(clamp.format_signed(i128::MIN), "-θΜαʹ");
+    }
+
+    #[test]
+    #[should_panic(expected = "10000 is above the maximum of 9999")]
+    fn overflow_policy_panic_test() {
+        GreekNumberFormatter::new()
+            .max_value(9999)
+            .overflow(OverflowPolicy::Panic)
+            .format_signed(10_000);
+    }
+
+    #[test]
+    fn leading_separator_test() {
+        let formatter = GreekNumberFormatter::new()
+            .separator("• ")
+            .leading_separator(true);
+        assert_eq!(formatter.format(97_554), "• αΜθʹ• ͵ζφνδ");
+        assert_eq!(formatter.format(2_056_839_184), "• βΜκʹ• αΜ͵εχπγ• ͵θρπδ");
+        assert_eq!(formatter.format(241), "• σμαʹ");
+        assert_eq!(formatter.format(0), "\u{1018A}");
+        assert_eq!(
+            formatter.leading_separator(false).format(97_554),
+            "αΜθʹ• ͵ζφνδ"
+        );
+    }
+
+    #[test]
+    fn overline_last_group_only_test() {
+        let formatter = GreekNumberFormatter::new().keraia(KeraiaStyle::Overline);
+        assert_eq!(
+            formatter.format(97_554),
+            "αΜθ\u{0305}, ͵ζ\u{0305}φ\u{0305}ν\u{0305}δ\u{0305}"
+        );
+
+        let formatter = formatter.overline(OverlineStyle::LastGroupOnly);
+        let greek = formatter.format(97_554);
+        assert_eq!(greek, "αΜθ, ͵ζ\u{0305}φ\u{0305}ν\u{0305}δ\u{0305}");
+        let (first, last) = greek.split_once(", ").unwrap();
+        assert!(!first.contains('\u{0305}'));
+        assert_eq!(last.matches('\u{0305}').count(), 4);
+
+        // a single group is the last one
+        assert_eq!(formatter.format(241), "σ\u{0305}μ\u{0305}α\u{0305}");
+        assert_eq!(from_greek(&formatter.format(241)), Ok(241));
+        // without the overline the style changes nothing
+        assert_eq!(
+            GreekNumberFormatter::new()
+                .overline(OverlineStyle::LastGroupOnly)
+                .format(97_554),
+            "αΜθʹ, ͵ζφνδ"
+        );
+    }
+
+    #[test]
+    fn grouping_test() {
+        let formatter = GreekNumberFormatter::new().grouping("\u{2009}");
+        assert_eq!(formatter.format(5683), "͵ε\u{2009}χ\u{2009}π\u{2009}γ");
+        assert_eq!(formatter.format(241), "σ\u{2009}μ\u{2009}αʹ");
+        assert_eq!(formatter.format(1), "αʹ");
+        assert_eq!(
+            formatter.format(2_056_839_184),
+            "βΜκʹ, αΜ͵ε\u{2009}χ\u{2009}π\u{2009}γ, ͵θ\u{2009}ρ\u{2009}π\u{2009}δ"
+        );
+        assert_eq!(formatter.char_len(5683).unwrap(), 8);
+        assert_eq!(GreekNumberFormatter::new().format(5683), "͵εχπγ");
+    }
+}
@@ -0,0 +1,73 @@
+use alloc::string::String;
+
+use crate::to_greek_lowercase;
+
+/// The sign for one half, U+2220 ANGLE followed by the keraia.
+const HALF: &str = "\u{2220}\u{0374}";
+/// U+2033 DOUBLE PRIME, the double keraia that marks a numeral as a denominator.
+const DOUBLE_KERAIA: char = '\u{2033}';
+
+/// Stringify a fraction to Greek numbers in lowercase
+///
+/// Unit fractions are written as their denominator marked with a double keraia `″` in place of the keraia, e.g. `γ″`
+/// for 1/3, except 1/2, which has its own sign `∠ʹ`. Other fractions are the numerator as usual followed by a space and
+/// the marked denominator, e.g. `βʹ ε″` for 2/5. The fraction is not reduced.
+///
+/// # Panics
+///
+/// Panics if `denominator` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use greek_number::to_greek_fraction;
+///
+/// let greek = to_greek_fraction(1, 3);
+/// println!("{}", greek); // "γ″"
+///
+/// let greek = to_greek_fraction(2, 5);
+/// println!("{}", greek); // "βʹ ε″"
+/// ```
+pub fn to_greek_fraction(numerator: usize, denominator: usize) -> String {
+    assert!(
+        denominator != 0,
+        "the denominator of a fraction cannot be 0"
+    );
+
+    if numerator == 1 && denominator == 2 {
+        return String::from(HALF);
+    }
+    let mut fraction = String::new();
+    if numerator != 1 {
+        fraction.push_str(&to_greek_lowercase(numerator));
+        fraction.push(' ');
+    }
+    let mut denominator = to_greek_lowercase(denominator);
+    if denominator.ends_with('\u{0374}') {
+        denominator.pop();
+    }
+    fraction.push_str(&denominator);
+    fraction.push(DOUBLE_KERAIA);
+    fraction
+}
+
+#[cfg(test)]
+mod tests {
+    use super::to_greek_fraction;
+
+    #[test]
+    fn to_greek_fraction_test() {
+        assert_eq!(to_greek_fraction(1, 2), "\u{2220}\u{0374}");
+        assert_eq!(to_greek_fraction(1, 3), "γ\u{2033}");
+        assert_eq!(to_greek_fraction(1, 241), "σμα\u{2033}");
+        assert_eq!(to_greek_fraction(1, 5683), "͵εχπγ\u{2033}");
+        assert_eq!(to_greek_fraction(2, 5), "β\u{0374} ε\u{2033}");
+        assert_eq!(to_greek_fraction(3, 2), "γ\u{0374} β\u{2033}");
+    }
+
+    #[test]
+    #[should_panic]
+    fn to_greek_fraction_zero_denominator_test() {
+        to_greek_fraction(1, 0);
+    }
+}
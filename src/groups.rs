@@ -0,0 +1,122 @@
+/// One myriad group of a number: its myriad power and its four decimal digits
+///
+/// A number is split into groups of four decimal digits from the right; the group with power `p` is worth its digits
+/// times 10000^p. Every digit is between 0 and 9.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GreekGroup {
+    power: usize,
+    thousands: usize,
+    hundreds: usize,
+    tens: usize,
+    ones: usize,
+}
+
+impl GreekGroup {
+    fn new(power: usize, value: usize) -> Self {
+        GreekGroup {
+            power,
+            thousands: value / 1000,
+            hundreds: value / 100 % 10,
+            tens: value / 10 % 10,
+            ones: value % 10,
+        }
+    }
+
+    /// The myriad power, written as the prefix before `Μ` (0 for the group without one).
+    pub fn power(&self) -> usize {
+        self.power
+    }
+
+    /// The thousands digit, written after the thousands sign `͵`.
+    pub fn thousands(&self) -> usize {
+        self.thousands
+    }
+
+    /// The hundreds digit.
+    pub fn hundreds(&self) -> usize {
+        self.hundreds
+    }
+
+    /// The tens digit.
+    pub fn tens(&self) -> usize {
+        self.tens
+    }
+
+    /// The ones digit.
+    pub fn ones(&self) -> usize {
+        self.ones
+    }
+
+    /// What the group is worth: its digits times 10000^power.
+    pub(crate) fn value(&self) -> u128 {
+        let digits = self.thousands * 1000 + self.hundreds * 100 + self.tens * 10 + self.ones;
+        digits as u128 * 10_000u128.pow(self.power as u32)
+    }
+}
+
+/// Split a number into the myriad groups it is written with
+///
+/// Groups come from the highest myriad power down. Groups whose digits are all zero are not written, so they are
+/// skipped; 0 itself has no groups.
+///
+/// # Examples
+///
+/// ```
+/// use greek_number::greek_groups;
+///
+/// for group in greek_groups(97_554) {
+///     println!("{} {}{}{}{}", group.power(), group.thousands(), group.hundreds(), group.tens(), group.ones());
+/// }
+/// // "1 0009"
+/// // "0 7554"
+/// ```
+pub fn greek_groups(n: usize) -> impl Iterator<Item = GreekGroup> {
+    groups(n as u128)
+}
+
+pub(crate) fn groups(n: u128) -> impl Iterator<Item = GreekGroup> {
+    let top = n.checked_ilog(10_000).unwrap_or(0);
+    (0..=top).rev().filter_map(move |power| {
+        let value = (n / 10_000u128.pow(power) % 10_000) as usize;
+        (value != 0).then(|| GreekGroup::new(power as usize, value))
+    })
+}
+
+/// Split decimal digits, most significant first, into myriad groups as [`groups`] does for a number.
+///
+/// Unlike [`groups`] this is not limited to a `u128`: it reaches as many groups as there are digits.
+pub(crate) fn digit_groups(digits: &[u8]) -> impl Iterator<Item = GreekGroup> + '_ {
+    let top = digits.len().saturating_sub(1) / 4;
+    (0..=top).rev().filter_map(move |power| {
+        let end = digits.len() - 4 * power;
+        let value = digits[end.saturating_sub(4)..end]
+            .iter()
+            .fold(0, |value, &digit| value * 10 + digit as usize);
+        (value != 0).then(|| GreekGroup::new(power, value))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::{greek_groups, GreekGroup};
+
+    #[test]
+    fn greek_groups_test() {
+        let digits = |n| {
+            greek_groups(n)
+                .map(|g: GreekGroup| (g.power(), [g.thousands(), g.hundreds(), g.tens(), g.ones()]))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(digits(0), []);
+        assert_eq!(digits(241), [(0, [0, 2, 4, 1])]);
+        assert_eq!(digits(97_554), [(1, [0, 0, 0, 9]), (0, [7, 5, 5, 4])]);
+        assert_eq!(
+            digits(2_056_839_184),
+            [(2, [0, 0, 2, 0]), (1, [5, 6, 8, 3]), (0, [9, 1, 8, 4])]
+        );
+        assert_eq!(digits(2_000_000_000), [(2, [0, 0, 2, 0])]);
+        assert_eq!(digits(100_000_001), [(2, [0, 0, 0, 1]), (0, [0, 0, 0, 1])]);
+    }
+}
@@ -0,0 +1,100 @@
+use alloc::string::String;
+use core::fmt::{self, Write};
+
+use crate::{Case, GreekNumberFormatter, Script};
+
+/// The most bytes of markup [`Script::Html`] writes for a sign or around a group: the thousands sign span.
+pub(crate) const HTML_MAX_MARKUP_LEN: usize = r#"<span class="greek-thousands"></span>"#.len();
+
+/// The bytes of markup around the whole numeral.
+pub(crate) const HTML_NUMBER_MARKUP_LEN: usize = r#"<span class="greek-number"></span>"#.len();
+
+/// A writer that puts the numeral signs written through it in their own `<span>`.
+pub(crate) struct HtmlWriter<'a, W: Write>(pub(crate) &'a mut W);
+
+impl<W: Write> Write for HtmlWriter<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        s.chars().try_for_each(|c| self.write_char(c))
+    }
+
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        let class = match c {
+            '\u{0374}' | '\u{02B9}' => "greek-keraia",
+            '\u{0375}' => "greek-thousands",
+            c => return self.0.write_char(c),
+        };
+        write!(self.0, r#"<span class="{}">{}</span>"#, class, c)
+    }
+}
+
+/// Stringify a number to Greek numbers as HTML
+///
+/// The numeral is wrapped in `<span class="greek-number">`, and each myriad group in `<span class="greek-group">`,
+/// separated by `", "`. Within a group the keraia is wrapped in `<span class="greek-keraia">` and the thousands sign in
+/// `<span class="greek-thousands">`, so that CSS can style them. These class names are stable. For other options, use
+/// a [`GreekNumberFormatter`] with [`Script::Html`].
+///
+/// # Examples
+///
+/// ```
+/// use greek_number::{to_greek_html, Case};
+///
+/// let html = to_greek_html(241, Case::Lower);
+/// println!("{}", html);
+/// // <span class="greek-number"><span class="greek-group">σμα<span class="greek-keraia">ʹ</span></span></span>
+/// ```
+pub fn to_greek_html(n: usize, case: Case) -> String {
+    GreekNumberFormatter::new()
+        .case(case)
+        .script(Script::Html)
+        .format(n)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::to_greek_html;
+    use crate::{Case, GreekNumberFormatter, KeraiaStyle, Script, SixStyle};
+
+    #[test]
+    fn to_greek_html_test() {
+        assert_eq!(
+            to_greek_html(97_554, Case::Lower),
+            concat!(
+                r#"<span class="greek-number">"#,
+                r#"<span class="greek-group">αΜθ<span class="greek-keraia">ʹ</span></span>, "#,
+                r#"<span class="greek-group"><span class="greek-thousands">͵</span>ζφνδ</span>"#,
+                "</span>",
+            )
+        );
+        assert_eq!(
+            to_greek_html(241, Case::Upper),
+            r#"<span class="greek-number"><span class="greek-group">ΣΜΑ<span class="greek-keraia">ʹ</span></span></span>"#
+        );
+        assert_eq!(
+            to_greek_html(0, Case::Lower),
+            r#"<span class="greek-number">𐆊</span>"#
+        );
+    }
+
+    #[test]
+    fn html_script_options_test() {
+        let formatter = GreekNumberFormatter::new()
+            .script(Script::Html)
+            .separator(" · ")
+            .six(SixStyle::SigmaTau)
+            .keraia(KeraiaStyle::ModifierPrime);
+        assert_eq!(
+            formatter.format(60_006),
+            concat!(
+                r#"<span class="greek-number">"#,
+                r#"<span class="greek-group">αΜστ<span class="greek-keraia">ʹ</span></span> · "#,
+                r#"<span class="greek-group">στ<span class="greek-keraia">ʹ</span></span>"#,
+                "</span>",
+            )
+        );
+        assert_eq!(
+            formatter.format(0),
+            r#"<span class="greek-number">𐆊</span>"#
+        );
+    }
+}
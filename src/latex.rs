@@ -0,0 +1,43 @@
+use core::fmt::{self, Write};
+
+/// The LaTeX markup for a character of a numeral, if it needs any.
+///
+/// The numeral signs get the `textalpha` macros, and the characters special to LaTeX, which can only come from the
+/// separator, are escaped.
+fn latex(c: char) -> Option<&'static str> {
+    let markup = match c {
+        '\u{0374}' | '\u{02B9}' => "\\textnumeralsigngreek{}",
+        '\u{0375}' => "\\textnumeralsignlowergreek{}",
+        '\\' => "\\textbackslash{}",
+        '~' => "\\textasciitilde{}",
+        '^' => "\\textasciicircum{}",
+        '{' => "\\{",
+        '}' => "\\}",
+        '#' => "\\#",
+        '$' => "\\$",
+        '%' => "\\%",
+        '&' => "\\&",
+        '_' => "\\_",
+        _ => return None,
+    };
+    Some(markup)
+}
+
+/// The most bytes [`LatexWriter`] writes for one character.
+pub(crate) const MAX_MARKUP_LEN: usize = "\\textnumeralsignlowergreek{}".len();
+
+/// A writer that turns everything written through it into LaTeX markup.
+pub(crate) struct LatexWriter<'a, W: Write>(pub(crate) &'a mut W);
+
+impl<W: Write> Write for LatexWriter<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        s.chars().try_for_each(|c| self.write_char(c))
+    }
+
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        match latex(c) {
+            Some(markup) => self.0.write_str(markup),
+            None => self.0.write_char(c),
+        }
+    }
+}
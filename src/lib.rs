@@ -1,3 +1,50 @@
+#![no_std]
+
+extern crate alloc;
+#[cfg(any(feature = "std", test))]
+extern crate std;
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::num::NonZeroUsize;
+
+mod attic;
+mod beta_code;
+mod format;
+mod fraction;
+mod groups;
+mod html;
+mod latex;
+mod number;
+mod parse;
+mod roman;
+mod svg;
+pub mod tables;
+
+pub use attic::{checked_to_attic, to_attic};
+#[cfg(feature = "unicode-normalization")]
+pub use format::Normalization;
+pub use format::{
+    Alphabet, FallbackStyle, GreekNumberFormatter, KeraiaStyle, MyriadStyle, OverflowPolicy,
+    OverlineStyle, SampiStyle, Script, Separator, SixStyle, ThousandsStyle, ZeroStyle,
+};
+pub use fraction::to_greek_fraction;
+pub use groups::{greek_groups, GreekGroup};
+pub use html::to_greek_html;
+pub use number::GreekNumber;
+pub use parse::{
+    detect_case, from_greek, from_greek_ignore_case, from_greek_lenient, from_greek_with,
+    greek_cmp, greek_to_usize, is_valid_greek_number, letter_value, parse_all, GreekParseError,
+    GreekParseErrorKind,
+};
+pub use roman::roman_to_greek;
+pub use svg::{to_greek_svg, SvgOptions};
+use tables::KERAIA;
+pub use tables::{greek_digit, GREEK_NUMERALS};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Case {
     /// Everything is lowercased.
     Lower,
@@ -44,141 +91,931 @@ pub fn to_greek_uppercase(n: usize) -> String {
     to_greek(n, Case::Upper)
 }
 
-#[allow(non_snake_case)]
-fn to_greek(n: usize, case: Case) -> String {
+/// Stringify a number to Greek numbers with only the first letter capitalized
+///
+/// This is the lowercase form with its first digit letter uppercased, for prose like "Chapter Σμαʹ". The thousands
+/// sign and the myriad prefix are not digit letters, so they are skipped: the prefix stays lowercase.
+///
+/// # Examples
+///
+/// ```
+/// use greek_number::to_greek_title_case;
+///
+/// let greek = to_greek_title_case(241);
+/// println!("{}", greek); // "Σμαʹ"
+///
+/// let greek = to_greek_title_case(5683);
+/// println!("{}", greek); // "͵Εχπγ"
+/// ```
+pub fn to_greek_title_case(n: usize) -> String {
+    let lower = to_greek(n, Case::Lower);
+    let mut title = String::with_capacity(lower.len());
+    let mut chars = lower.chars().peekable();
+    while let Some(c) = chars.next() {
+        // A lowercase letter followed by `Μ` is a myriad prefix, not a digit.
+        if c.is_lowercase() && chars.peek() != Some(&'Μ') {
+            title.extend(c.to_uppercase());
+            title.extend(chars);
+            break;
+        }
+        title.push(c);
+    }
+    title
+}
+
+/// The `char`s of a number's Greek numeral, to see exactly which code points it is made of
+///
+/// # Examples
+///
+/// ```
+/// use greek_number::{to_greek_chars, Case};
+///
+/// let chars = to_greek_chars(1, Case::Lower);
+/// println!("{:?}", chars); // ['α', '\u{374}']
+/// ```
+pub fn to_greek_chars(n: usize, case: Case) -> Vec<char> {
+    to_greek(n, case).chars().collect()
+}
+
+/// Stringify the numbers from `start` up to but not including `end` to Greek numbers, one at a time
+///
+/// Each numeral is only built when the iterator gets to it. Like `start..end`, the range is empty if `end` is not
+/// above `start`.
+///
+/// # Examples
+///
+/// ```
+/// use greek_number::{greek_range, Case};
+///
+/// for greek in greek_range(1, 4, Case::Lower) {
+///     println!("{}", greek);
+/// }
+/// // "αʹ"
+/// // "βʹ"
+/// // "γʹ"
+/// ```
+pub fn greek_range(start: usize, end: usize, case: Case) -> impl Iterator<Item = String> {
+    (start..end).map(move |n| to_greek(n, case))
+}
+
+// The numerals of 0 to 9 in lowercase and uppercase, which `to_greek_cow` borrows instead of allocating.
+const SINGLE_DIGITS: [[&str; 2]; 10] = [
+    ["𐆊", "𐆊"],
+    ["αʹ", "Αʹ"],
+    ["βʹ", "Βʹ"],
+    ["γʹ", "Γʹ"],
+    ["δʹ", "Δʹ"],
+    ["εʹ", "Εʹ"],
+    ["ϛʹ", "Ϛʹ"],
+    ["ζʹ", "Ζʹ"],
+    ["ηʹ", "Ηʹ"],
+    ["θʹ", "Θʹ"],
+];
+
+/// Stringify a number to Greek numbers, without allocating for the single digits
+///
+/// The numerals of 0 to 9 are borrowed from a static table; every other number is an owned `String`.
+///
+/// # Examples
+///
+/// ```
+/// use std::borrow::Cow;
+/// use greek_number::{to_greek_cow, Case};
+///
+/// let greek = to_greek_cow(1, Case::Lower);
+/// println!("{}", greek); // "αʹ"
+/// assert!(matches!(greek, Cow::Borrowed(_)));
+///
+/// let greek = to_greek_cow(241, Case::Lower);
+/// println!("{}", greek); // "σμαʹ"
+/// assert!(matches!(greek, Cow::Owned(_)));
+/// ```
+pub fn to_greek_cow(n: usize, case: Case) -> Cow<'static, str> {
+    let Some(numerals) = SINGLE_DIGITS.get(n) else {
+        return Cow::Owned(to_greek(n, case));
+    };
+    Cow::Borrowed(match case {
+        Case::Lower => numerals[0],
+        Case::Upper => numerals[1],
+    })
+}
+
+/// Stringify a number to Greek numbers, with the final keraia split off from the letters
+///
+/// The keraia is `None` when the numeral does not end with one, as when its last group has a thousands sign. Keraias
+/// of the earlier myriad groups stay in the letters.
+///
+/// # Examples
+///
+/// ```
+/// use greek_number::{to_greek_parts, Case};
+///
+/// let (letters, keraia) = to_greek_parts(241, Case::Lower);
+/// println!("{} {:?}", letters, keraia); // "σμα Some('\u{374}')"
+///
+/// let (letters, keraia) = to_greek_parts(5683, Case::Lower);
+/// println!("{} {:?}", letters, keraia); // "͵εχπγ None"
+/// ```
+pub fn to_greek_parts(n: usize, case: Case) -> (String, Option<char>) {
+    let mut letters = to_greek(n, case);
+    let keraia = letters.ends_with(KERAIA).then(|| {
+        letters.truncate(letters.len() - KERAIA.len_utf8());
+        KERAIA
+    });
+    (letters, keraia)
+}
+
+/// Stringify a number to Greek numbers that fit in `max_graphemes` grapheme clusters, cut with an ellipsis if needed
+///
+/// See [`GreekNumberFormatter::format_truncated`] for the details.
+///
+/// # Examples
+///
+/// ```
+/// use greek_number::{to_greek_truncated, Case};
+///
+/// let greek = to_greek_truncated(97_554, Case::Lower, 6);
+/// println!("{}", greek); // "αΜθʹ,…"
+/// ```
+#[cfg(feature = "unicode-segmentation")]
+pub fn to_greek_truncated(n: usize, case: Case, max_graphemes: usize) -> String {
+    GreekNumberFormatter::new()
+        .case(case)
+        .format_truncated(n, max_graphemes)
+}
+
+/// The number of terminal columns a number is displayed in as Greek numbers
+///
+/// See [`GreekNumberFormatter::display_width`] for the details.
+///
+/// # Examples
+///
+/// ```
+/// use greek_number::{greek_display_width, Case};
+///
+/// println!("{}", greek_display_width(97_554, Case::Lower)); // 11
+/// ```
+#[cfg(feature = "unicode-width")]
+pub fn greek_display_width(n: usize, case: Case) -> usize {
+    GreekNumberFormatter::new().case(case).display_width(n)
+}
+
+/// Stringify a number to Greek numbers in both cases, as `(lowercase, uppercase)`
+///
+/// The number is split into myriad groups only once, and the same groups are written in each case by the formatter's
+/// group writer, so the two always line up letter for letter.
+///
+/// # Examples
+///
+/// ```
+/// use greek_number::to_greek_both;
+///
+/// let (lower, upper) = to_greek_both(97_554);
+/// println!("{}", lower); // "αΜθʹ, ͵ζφνδ"
+/// println!("{}", upper); // "αΜΘʹ, ͵ΖΦΝΔ"
+/// ```
+pub fn to_greek_both(n: usize) -> (String, String) {
+    if n == 0 {
+        let zero = to_greek(0, Case::Lower);
+        return (zero.clone(), zero);
+    }
+    let groups: Vec<GreekGroup> = greek_groups(n).collect();
+    let [lower, upper] = [Case::Lower, Case::Upper].map(|case| {
+        let mut greek = String::new();
+        GreekNumberFormatter::new()
+            .case(case)
+            .write_groups(groups.iter().copied(), &mut greek)
+            .expect("writing to a String cannot fail");
+        greek
+    });
+    (lower, upper)
+}
+
+/// Stringify a number to a Greek ordinal ("first", "second", ...)
+///
+/// The convention used is the modern one for ordinals written in digits, as in `1ος`: the numeral is followed by the
+/// masculine ending `ος`. The ending is always lowercase, also after an uppercase numeral.
+///
+/// # Examples
+///
+/// ```
+/// use greek_number::{to_greek_ordinal, Case};
+///
+/// let ordinal = to_greek_ordinal(1, Case::Lower);
+/// println!("{}", ordinal); // "αʹος"
+///
+/// let ordinal = to_greek_ordinal(2, Case::Upper);
+/// println!("{}", ordinal); // "Βʹος"
+/// ```
+pub fn to_greek_ordinal(n: usize, case: Case) -> String {
+    let mut ordinal = to_greek(n, case);
+    ordinal.push_str("ος");
+    ordinal
+}
+
+/// Stringify a `u128` to Greek numbers
+///
+/// This reaches the nine M powers of the representation: `u128::MAX` is about 3.4 × 10^38, which needs the highest
+/// myriad prefix `θΜ` (10000^9). It is still below 10^40 - 1, so this never panics.
+///
+/// # Examples
+///
+/// ```
+/// use greek_number::{to_greek_u128, Case};
+///
+/// let greek = to_greek_u128(10u128.pow(36), Case::Lower);
+/// println!("{}", greek); // "θΜαʹ"
+/// ```
+pub fn to_greek_u128(n: u128, case: Case) -> String {
+    try_to_greek(n, case).expect("every u128 is below 10^40")
+}
+
+/// Stringify a number that cannot be zero to Greek numbers
+///
+/// The output is that of [`to_greek_lowercase`] or [`to_greek_uppercase`]; as the number is not zero, it is always
+/// written with letters and a keraia rather than the zero sign.
+///
+/// # Examples
+///
+/// ```
+/// use core::num::NonZeroUsize;
+/// use greek_number::{to_greek_nonzero, Case};
+///
+/// let greek = to_greek_nonzero(NonZeroUsize::new(241).unwrap(), Case::Lower);
+/// println!("{}", greek); // "σμαʹ"
+/// ```
+pub fn to_greek_nonzero(n: NonZeroUsize, case: Case) -> String {
+    to_greek(n.get(), case)
+}
+
+/// Stringify a signed number to Greek numbers
+///
+/// Negative numbers get a `-` before the numeral; use [`GreekNumberFormatter::negative_sign`] for another sign. Zero
+/// is the zero sign with no sign.
+///
+/// # Examples
+///
+/// ```
+/// use greek_number::{to_greek_signed, Case};
+///
+/// let greek = to_greek_signed(-241, Case::Lower);
+/// println!("{}", greek); // "-σμαʹ"
+/// ```
+pub fn to_greek_signed(n: i128, case: Case) -> String {
+    GreekNumberFormatter::new().case(case).format_signed(n)
+}
+
+/// Stringify unsigned integers to Greek numbers
+///
+/// # Examples
+///
+/// ```
+/// use greek_number::{Case, ToGreek};
+///
+/// let greek = 42u8.to_greek(Case::Lower);
+/// println!("{}", greek); // "μβʹ"
+/// ```
+pub trait ToGreek {
+    /// Stringify the number to Greek numbers in the given case
+    fn to_greek(&self, case: Case) -> String;
+}
+
+macro_rules! impl_to_greek {
+    ($($t:ty),*) => {
+        $(
+            impl ToGreek for $t {
+                fn to_greek(&self, case: Case) -> String {
+                    to_greek_u128(*self as u128, case)
+                }
+            }
+        )*
+    };
+}
+
+impl_to_greek!(u8, u16, u32, u64, u128, usize);
+
+/// Append a number as Greek numbers to an existing `String`
+///
+/// The numeral is appended after whatever `out` already holds, so the same buffer (and its capacity) can be reused
+/// across calls.
+///
+/// # Examples
+///
+/// ```
+/// use greek_number::{to_greek_into, Case};
+///
+/// let mut greek = String::new();
+/// to_greek_into(1, Case::Lower, &mut greek);
+/// greek.push(' ');
+/// to_greek_into(241, Case::Lower, &mut greek);
+/// println!("{}", greek); // "αʹ σμαʹ"
+/// ```
+pub fn to_greek_into(n: usize, case: Case, out: &mut String) {
+    GreekNumberFormatter::new().case(case).format_into(n, out)
+}
+
+/// The number of `char`s a number takes as Greek numbers
+///
+/// This is `to_greek_lowercase(n).chars().count()` (or the uppercase one, which is just as long), counted without
+/// building the `String`. The myriad prefixes, the separators and the keraias all count. Note that this is not the
+/// display width: a terminal may show some of these characters wider or narrower.
+///
+/// # Examples
+///
+/// ```
+/// use greek_number::{greek_len, Case};
+///
+/// println!("{}", greek_len(241, Case::Lower)); // 4
+/// println!("{}", greek_len(97_554, Case::Lower)); // 11
+/// ```
+pub fn greek_len(n: usize, case: Case) -> usize {
+    GreekNumberFormatter::new()
+        .case(case)
+        .char_len(n as u128)
+        .expect("every usize is below 10^40")
+}
+
+/// Stringify numbers to Greek numbers right-aligned to a common width
+///
+/// Each numeral is padded on the left with spaces to the width of the longest one, so they line up in a table. With the
+/// `unicode-width` feature the width is counted in terminal columns, otherwise it is the [`greek_len`] in `char`s.
+/// These numerals have no combining marks, so both are the same.
+///
+/// # Examples
+///
+/// ```
+/// use greek_number::{to_greek_padded, Case};
+///
+/// let greek = to_greek_padded(&[1, 241], Case::Lower);
+/// println!("{:?}", greek); // ["  αʹ", "σμαʹ"]
+/// ```
+pub fn to_greek_padded(values: &[usize], case: Case) -> Vec<String> {
+    pad_numerals(&GreekNumberFormatter::new().case(case), values)
+}
+
+/// Write each number with `formatter`, padded on the left with spaces to the width of the widest numeral.
+fn pad_numerals(formatter: &GreekNumberFormatter, values: &[usize]) -> Vec<String> {
+    #[cfg(feature = "unicode-width")]
+    let width_of = |n: usize| formatter.display_width(n);
+    #[cfg(not(feature = "unicode-width"))]
+    let width_of = |n: usize| {
+        formatter
+            .char_len(n as u128)
+            .expect("every usize is below 10^40")
+    };
+    let width = values.iter().map(|&n| width_of(n)).max().unwrap_or(0);
+    values
+        .iter()
+        .map(|&n| {
+            let mut padded = " ".repeat(width - width_of(n));
+            padded.push_str(&formatter.format(n));
+            padded
+        })
+        .collect()
+}
+
+/// Stringify several numbers to Greek numbers joined by `sep`, e.g. for a book and section reference
+///
+/// Each part is its own numeral with its own keraia. No parts give the empty string.
+///
+/// # Examples
+///
+/// ```
+/// use greek_number::{to_greek_composite, Case};
+///
+/// let reference = to_greek_composite(&[2, 3, 241], Case::Lower, ".");
+/// println!("{}", reference); // "βʹ.γʹ.σμαʹ"
+/// ```
+pub fn to_greek_composite(parts: &[usize], case: Case, sep: &str) -> String {
+    let mut composite = String::new();
+    for (i, &n) in parts.iter().enumerate() {
+        if i > 0 {
+            composite.push_str(sep);
+        }
+        to_greek_into(n, case, &mut composite);
+    }
+    composite
+}
+
+/// Stringify a number to Greek numbers, one string per myriad group
+///
+/// The groups come from the highest myriad power down, each with its own myriad prefix and keraia but without the
+/// separator, so they can be joined or laid out as needed. 0 is the single group `𐆊`.
+///
+/// # Examples
+///
+/// ```
+/// use greek_number::{to_greek_groups_str, Case};
+///
+/// let groups = to_greek_groups_str(97_554, Case::Lower);
+/// println!("{:?}", groups); // ["αΜθʹ", "͵ζφνδ"]
+/// ```
+pub fn to_greek_groups_str(n: usize, case: Case) -> Vec<String> {
     if n == 0 {
-        return '𐆊'.into(); // Greek Zero Sign https://www.compart.com/en/unicode/U+1018A
+        return vec![to_greek(0, case)];
     }
+    let formatter = GreekNumberFormatter::new().case(case);
+    groups::groups(n as u128)
+        .map(|group| {
+            let mut greek = String::new();
+            formatter
+                .try_format_into(group.value(), &mut greek)
+                .expect("every usize is below 10^40");
+            greek
+        })
+        .collect()
+}
 
-    let mut fmt = String::new();
+/// Split the numeral of a number into its digit glyphs, each with the value it adds to the number
+///
+/// A thousand comes with its thousands sign, e.g. `͵ε` for 5000, and the letters of a myriad group are worth their
+/// value times the myriad, so the values always add up to `n`. The myriad prefix, the keraia and the separators add
+/// nothing themselves and are left out. 0 is the single glyph `𐆊` worth 0.
+///
+/// # Examples
+///
+/// ```
+/// use greek_number::{annotate, Case};
+///
+/// println!("{:?}", annotate(241, Case::Lower)); // [("σ", 200), ("μ", 40), ("α", 1)]
+/// println!("{:?}", annotate(90_004, Case::Lower)); // [("θ", 90000), ("δ", 4)]
+/// ```
+pub fn annotate(n: usize, case: Case) -> Vec<(String, usize)> {
+    if n == 0 {
+        return vec![(to_greek(0, case), 0)];
+    }
     let case = match case {
         Case::Lower => 0,
         Case::Upper => 1,
     };
-    let thousands = [
-        ["͵α", "͵Α"],
-        ["͵β", "͵Β"],
-        ["͵γ", "͵Γ"],
-        ["͵δ", "͵Δ"],
-        ["͵ε", "͵Ε"],
-        ["͵ϛ", "͵Ϛ"],
-        ["͵ζ", "͵Ζ"],
-        ["͵η", "͵Η"],
-        ["͵θ", "͵Θ"],
-    ];
-    let hundreds = [
-        ["ρ", "Ρ"],
-        ["σ", "Σ"],
-        ["τ", "Τ"],
-        ["υ", "Υ"],
-        ["φ", "Φ"],
-        ["χ", "Χ"],
-        ["ψ", "Ψ"],
-        ["ω", "Ω"],
-        ["ϡ", "Ϡ"],
-    ];
-    let tens = [
-        ["ι", "Ι"],
-        ["κ", "Κ"],
-        ["λ", "Λ"],
-        ["μ", "Μ"],
-        ["ν", "Ν"],
-        ["ξ", "Ξ"],
-        ["ο", "Ο"],
-        ["π", "Π"],
-        ["ϙ", "Ϟ"],
-    ];
-    let ones = [
-        ["α", "Α"],
-        ["β", "Β"],
-        ["γ", "Γ"],
-        ["δ", "Δ"],
-        ["ε", "Ε"],
-        ["ϛ", "Ϛ"],
-        ["ζ", "Ζ"],
-        ["η", "Η"],
-        ["θ", "Θ"],
-    ];
-    // Extract a list of decimal digits from the number
-    let mut decimal_digits: Vec<usize> = Vec::new();
-    let mut n = n;
-    while n > 0 {
-        decimal_digits.push(n % 10);
-        n /= 10;
+    let mut glyphs = Vec::new();
+    for group in groups::groups(n as u128) {
+        let myriad = 10_000usize.pow(group.power() as u32);
+        let places = [
+            (group.thousands(), &tables::THOUSANDS, 1000),
+            (group.hundreds(), &tables::HUNDREDS, 100),
+            (group.tens(), &tables::TENS, 10),
+            (group.ones(), &tables::ONES, 1),
+        ];
+        for (digit, table, place) in places {
+            if digit != 0 {
+                glyphs.push((String::from(table[digit - 1][case]), digit * place * myriad));
+            }
+        }
     }
+    glyphs
+}
+
+/// Write a number as Greek numbers to any [`core::fmt::Write`]
+///
+/// Nothing is allocated: the letters go straight into `w`, e.g. a `core::fmt::Formatter` or a fixed size buffer.
+///
+/// # Examples
+///
+/// ```
+/// use greek_number::{write_greek, Case};
+///
+/// let mut greek = String::new();
+/// write_greek(&mut greek, 241, Case::Lower).unwrap();
+/// println!("{}", greek); // "σμαʹ"
+/// ```
+pub fn write_greek<W: core::fmt::Write>(w: &mut W, n: usize, case: Case) -> core::fmt::Result {
+    GreekNumberFormatter::new().case(case).write(n as u128, w)
+}
+
+/// Write a number as lowercase Greek numbers to any [`core::fmt::Write`]
+///
+/// This is [`to_greek_lowercase`] without the `String`; see [`write_greek`].
+///
+/// # Examples
+///
+/// ```
+/// use greek_number::write_greek_lowercase;
+///
+/// let mut greek = String::new();
+/// write_greek_lowercase(&mut greek, 241).unwrap();
+/// println!("{}", greek); // "σμαʹ"
+/// ```
+pub fn write_greek_lowercase<W: core::fmt::Write>(w: &mut W, n: usize) -> core::fmt::Result {
+    write_greek(w, n, Case::Lower)
+}
+
+/// Write a number as uppercase Greek numbers to any [`core::fmt::Write`]
+///
+/// This is [`to_greek_uppercase`] without the `String`; see [`write_greek`].
+///
+/// # Examples
+///
+/// ```
+/// use greek_number::write_greek_uppercase;
+///
+/// let mut greek = String::new();
+/// write_greek_uppercase(&mut greek, 241).unwrap();
+/// println!("{}", greek); // "ΣΜΑʹ"
+/// ```
+pub fn write_greek_uppercase<W: core::fmt::Write>(w: &mut W, n: usize) -> core::fmt::Result {
+    write_greek(w, n, Case::Upper)
+}
 
-    // Pad the digits with leading zeros to ensure we can form groups of 4
-    while decimal_digits.len() % 4 != 0 {
-        decimal_digits.push(0);
+/// A number that formats as Greek numbers, for use inline in `write!`, `format_args!` and logging macros
+///
+/// Nothing is built until the value is displayed, and displaying it writes the letters straight into the formatter,
+/// so a numeral that is only logged or written to a buffer costs no allocation. This is [`GreekNumber::new`] under a
+/// name that reads well in format arguments.
+///
+/// # Examples
+///
+/// ```
+/// use greek_number::{greek_fmt, Case};
+///
+/// let line = format!("Chapter {}", greek_fmt(241, Case::Upper));
+/// println!("{}", line); // "Chapter ΣΜΑʹ"
+/// ```
+pub fn greek_fmt(n: usize, case: Case) -> GreekNumber {
+    GreekNumber::new(n, case)
+}
+
+/// The error returned by [`to_greek_bytes`] when the numeral does not fit in the buffer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BufferTooSmall;
+
+impl core::fmt::Display for BufferTooSmall {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("buffer too small for the Greek number")
     }
-    decimal_digits.reverse();
+}
 
-    let mut M_power = decimal_digits.len() / 4 - 1;
+#[cfg(feature = "std")]
+impl std::error::Error for BufferTooSmall {}
 
-    // M are used to represent 10000, M_power = 2 means 10000^2 = 10000 0000
-    // The prefix of M is also made of Greek numerals but only be single digits, so it is 9 at max. This enables us
-    // to represent up to (10000)^(9 + 1) - 1 = 10^40 -1  (9,999,999,999,999,999,999,999,999,999,999,999,999,999)
-    let get_M_prefix = |M_power: usize| {
-        if M_power == 0 {
-            None
-        } else {
-            assert!(M_power <= 9);
-            // the prefix of M is a single digit lowercase
-            Some(ones[M_power - 1][0])
-        }
-    };
+/// A writer that fills a byte slice, failing once it is full.
+struct SliceWriter<'a> {
+    buf: &'a mut [u8],
+    len: usize,
+}
 
-    let mut previous_has_number = false;
-    for chunk in decimal_digits.chunks_exact(4) {
-        // chunk must be exact 4 item
-        assert_eq!(chunk.len(), 4);
+impl core::fmt::Write for SliceWriter<'_> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        let end = self.len + s.len();
+        self.buf
+            .get_mut(self.len..end)
+            .ok_or(core::fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
 
-        // `th`ousan, `h`undred, `t`en and `o`ne
-        let (th, h, t, o) = (chunk[0], chunk[1], chunk[2], chunk[3]);
-        if th + h + t + o == 0 {
-            continue;
-        }
+/// Write a number as Greek numbers into a byte buffer, without allocating
+///
+/// The numeral is written as UTF-8 at the start of `buf` and returned as a `&str` borrowing it. Each letter takes two
+/// bytes, so 64 bytes hold any `u32` and 128 bytes any `u64`.
+///
+/// # Examples
+///
+/// ```
+/// use greek_number::{to_greek_bytes, Case};
+///
+/// let mut buf = [0; 16];
+/// let greek = to_greek_bytes(241, Case::Lower, &mut buf).unwrap();
+/// println!("{}", greek); // "σμαʹ"
+/// ```
+pub fn to_greek_bytes(n: usize, case: Case, buf: &mut [u8]) -> Result<&str, BufferTooSmall> {
+    let mut writer = SliceWriter { buf, len: 0 };
+    write_greek(&mut writer, n, case).map_err(|_| BufferTooSmall)?;
+    let SliceWriter { buf, len } = writer;
+    Ok(core::str::from_utf8(&buf[..len]).expect("only whole strs are written"))
+}
 
-        if previous_has_number {
-            fmt.push_str(", ");
-        }
+fn to_greek(n: usize, case: Case) -> String {
+    if (1..10_000).contains(&n) {
+        return to_greek_small(n, case);
+    }
+    GreekNumberFormatter::new().case(case).format(n)
+}
 
-        if let Some(m_prefix) = get_M_prefix(M_power) {
-            fmt.push_str(m_prefix);
-            fmt.push('Μ');
-        }
-        if th != 0 {
-            let thousand_digit = thousands[th - 1][case];
-            fmt.push_str(thousand_digit);
-        }
-        if h != 0 {
-            let hundred_digit = hundreds[h - 1][case];
-            fmt.push_str(hundred_digit);
-        }
-        if t != 0 {
-            let ten_digit = tens[t - 1][case];
-            fmt.push_str(ten_digit);
-        }
-        if o != 0 {
-            let one_digit = ones[o - 1][case];
-            fmt.push_str(one_digit);
+/// The numeral of a number from 1 to 9999 with the default options, straight from the digit tables.
+///
+/// These are most numbers in practice and a single group without myriad, so this skips the formatter's options and
+/// its capacity estimate: a thousands sign and four letters, or three letters and a keraia, are at most 10 bytes.
+fn to_greek_small(n: usize, case: Case) -> String {
+    debug_assert!((1..10_000).contains(&n));
+    let case = match case {
+        Case::Lower => 0,
+        Case::Upper => 1,
+    };
+    let mut greek = String::with_capacity(10);
+    let places = [
+        (n / 1000, &tables::THOUSANDS),
+        (n / 100 % 10, &tables::HUNDREDS),
+        (n / 10 % 10, &tables::TENS),
+        (n % 10, &tables::ONES),
+    ];
+    for (digit, table) in places {
+        if digit != 0 {
+            greek.push_str(table[digit - 1][case]);
         }
-        // if we do not have thousan, we need to append 'ʹ' at the end.
-        if th == 0 {
-            fmt.push('ʹ');
+    }
+    if n < 1000 {
+        greek.push(KERAIA);
+    }
+    greek
+}
+
+/// The highest myriad power a numeral can have
+///
+/// The power is written as a single ones letter before `Μ`, and the ones only reach 9 (`θ`). So the highest group is
+/// worth 10000^9 times up to 9999, and the largest number with a numeral is 10000^10 - 1 = 10^40 - 1.
+pub const GREEK_MAX_MYRIAD_POWER: usize = 9;
+
+/// The most decimal digits a number with a numeral can have: 4 for each of the [`GREEK_MAX_MYRIAD_POWER`] + 1 groups
+///
+/// The ceiling itself, 10^40 - 1, does not fit in a `u128` (`u128::MAX` is about 3.4 × 10^38), so it is not given as
+/// a constant; every `u128` is in range. Use this to check numbers wider than a `u128`, e.g. as strings of digits.
+pub const GREEK_MAX_DIGITS: u32 = 4 * (GREEK_MAX_MYRIAD_POWER as u32 + 1);
+
+/// Whether the numeral of `n` has myriad groups, i.e. is written with `Μ`
+///
+/// That is the case from one myriad, 10000, up: numbers below it are a single group of at most four letters.
+///
+/// # Examples
+///
+/// ```
+/// use greek_number::uses_myriad;
+///
+/// println!("{}", uses_myriad(9999)); // false
+/// println!("{}", uses_myriad(10_000)); // true
+/// ```
+pub const fn uses_myriad(n: usize) -> bool {
+    n >= 10_000
+}
+
+/// Errors that can occur while stringifying a number to Greek numbers or parsing one
+///
+/// Every fallible operation of the crate has an error that converts into this one, so callers can use `?` for all of
+/// them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GreekNumberError {
+    /// The number needs more than ten myriad groups, so it is at least 10^40 and has no single digit M power
+    /// representation. Carries the value that could not be converted.
+    OutOfRange(u128),
+    /// A digit given to [`greek_from_digits`] is not between 0 and 9. Carries the offending digit.
+    InvalidDigit(u8),
+    /// The digits given to [`greek_from_digits`] make a number of more than [`GREEK_MAX_DIGITS`] digits, so it is at
+    /// least 10^40. Carries the number of digits after leading zeros.
+    TooManyDigits(usize),
+    /// The number is above the maximum set with [`GreekNumberFormatter::max_value`]. Carries the value and the maximum.
+    AboveMax(u128, u128),
+    /// The string is not a well-formed Greek number; the [`GreekParseError`] says what is wrong and where.
+    Parse(GreekParseError),
+}
+
+impl From<GreekParseError> for GreekNumberError {
+    fn from(error: GreekParseError) -> Self {
+        GreekNumberError::Parse(error)
+    }
+}
+
+impl core::fmt::Display for GreekNumberError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            GreekNumberError::OutOfRange(n) => {
+                write!(
+                    f,
+                    "{} is out of range for Greek numbers, which reach up to 10^40 - 1",
+                    n
+                )
+            }
+            GreekNumberError::InvalidDigit(digit) => write!(f, "{} is not a decimal digit", digit),
+            GreekNumberError::TooManyDigits(len) => write!(
+                f,
+                "a number of {} digits is out of range for Greek numbers, which reach up to 10^40 - 1",
+                len
+            ),
+            GreekNumberError::AboveMax(n, max) => {
+                write!(f, "{} is above the maximum of {} set for the formatter", n, max)
+            }
+            GreekNumberError::Parse(error) => write!(f, "invalid Greek number: {}", error),
         }
-        if M_power > 0 {
-            M_power = M_power.saturating_sub(1);
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for GreekNumberError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            GreekNumberError::OutOfRange(_)
+            | GreekNumberError::InvalidDigit(_)
+            | GreekNumberError::TooManyDigits(_)
+            | GreekNumberError::AboveMax(..) => None,
+            GreekNumberError::Parse(error) => Some(error),
         }
-        previous_has_number = true;
     }
-    fmt
+}
+
+/// Stringify a number to Greek numbers, returning an error instead of panicking if it is out of range
+///
+/// The single digit M power representation reaches up to 10^40 - 1. Note that `u128::MAX` is about 3.4 × 10^38, so
+/// this cannot fail for any built-in integer today; it lets callers handle the range check explicitly rather than rely
+/// on that.
+///
+/// # Examples
+///
+/// ```
+/// use greek_number::{try_to_greek, Case};
+///
+/// let greek = try_to_greek(241, Case::Lower);
+/// println!("{:?}", greek); // Ok("σμαʹ")
+/// ```
+pub fn try_to_greek(n: u128, case: Case) -> Result<String, GreekNumberError> {
+    GreekNumberFormatter::new().case(case).try_format(n)
+}
+
+/// Stringify a number to Greek numbers, returning `None` if it is out of range
+///
+/// This is [`try_to_greek`] for callers that just want to skip unrepresentable values. It returns `None` exactly when
+/// the number is at least 10^40, which no `u128` reaches, so for now it always returns `Some`.
+///
+/// # Examples
+///
+/// ```
+/// use greek_number::{checked_to_greek, Case};
+///
+/// let greek = checked_to_greek(241, Case::Lower);
+/// println!("{:?}", greek); // Some("σμαʹ")
+/// ```
+pub fn checked_to_greek(n: u128, case: Case) -> Option<String> {
+    try_to_greek(n, case).ok()
+}
+
+/// Stringify a number given by its decimal digits, most significant first, to Greek numbers
+///
+/// This is for numbers that are already split into digits, e.g. read from text or too wide for a `u128`: every number
+/// of up to [`GREEK_MAX_DIGITS`] digits can be written. Leading zeros are ignored, and no digits at all is zero.
+///
+/// # Examples
+///
+/// ```
+/// use greek_number::{greek_from_digits, Case};
+///
+/// let greek = greek_from_digits(&[2, 4, 1], Case::Lower);
+/// println!("{:?}", greek); // Ok("σμαʹ")
+/// ```
+pub fn greek_from_digits(digits: &[u8], case: Case) -> Result<String, GreekNumberError> {
+    if let Some(&digit) = digits.iter().find(|&&digit| digit > 9) {
+        return Err(GreekNumberError::InvalidDigit(digit));
+    }
+    let start = digits
+        .iter()
+        .position(|&digit| digit != 0)
+        .unwrap_or(digits.len());
+    let digits = &digits[start..];
+    if digits.len() > GREEK_MAX_DIGITS as usize {
+        return Err(GreekNumberError::TooManyDigits(digits.len()));
+    }
+    let mut greek = String::new();
+    GreekNumberFormatter::new()
+        .case(case)
+        .write_digits(digits, &mut greek)
+        .expect("writing to a String cannot fail");
+    Ok(greek)
+}
+
+/// Stringify 10^`exp` to Greek numbers
+///
+/// The numeral is built straight from the place of its single digit, so it is cheap even beyond the `u128` range:
+/// the digit is `α`, `ι`, `ρ` or `͵α` within its myriad group, after the prefix of the myriad power from 10^4 up.
+/// Exponents above 39 have no numeral and are [`GreekNumberError::TooManyDigits`].
+///
+/// # Examples
+///
+/// ```
+/// use greek_number::{greek_power_of_ten, Case};
+///
+/// println!("{:?}", greek_power_of_ten(2, Case::Lower)); // Ok("ρʹ")
+/// println!("{:?}", greek_power_of_ten(7, Case::Upper)); // Ok("αΜ͵Α")
+/// ```
+pub fn greek_power_of_ten(exp: u32, case: Case) -> Result<String, GreekNumberError> {
+    if exp >= GREEK_MAX_DIGITS {
+        return Err(GreekNumberError::TooManyDigits(
+            (exp as usize).saturating_add(1),
+        ));
+    }
+    let power = (exp / 4) as usize;
+    let case = match case {
+        Case::Lower => 0,
+        Case::Upper => 1,
+    };
+    let mut greek = String::with_capacity(8);
+    if power > 0 {
+        // the prefix of M is a single digit lowercase
+        greek.push_str(tables::ONES[power - 1][0]);
+        greek.push('Μ');
+    }
+    let table = [
+        &tables::ONES,
+        &tables::TENS,
+        &tables::HUNDREDS,
+        &tables::THOUSANDS,
+    ][exp as usize % 4];
+    greek.push_str(table[0][case]);
+    // a thousands sign marks the number already
+    if exp % 4 != 3 {
+        greek.push(KERAIA);
+    }
+    Ok(greek)
+}
+
+/// The decimal digits of a number, most significant first, padded with leading zeros to whole myriad groups
+///
+/// Every four digits are one myriad group, as the numerals are written, so the result can be fed to
+/// [`greek_from_digits`] or split with `chunks(4)` for a custom renderer. 0 is the single group `[0, 0, 0, 0]`.
+///
+/// # Examples
+///
+/// ```
+/// use greek_number::decimal_digits_msb_first;
+///
+/// println!("{:?}", decimal_digits_msb_first(97_554)); // [0, 0, 0, 9, 7, 5, 5, 4]
+/// println!("{:?}", decimal_digits_msb_first(1)); // [0, 0, 0, 1]
+/// ```
+pub fn decimal_digits_msb_first(n: u128) -> Vec<u8> {
+    let groups = n.checked_ilog(10_000).unwrap_or(0) + 1;
+    let mut digits = vec![0; 4 * groups as usize];
+    let mut rest = n;
+    for digit in digits.iter_mut().rev() {
+        *digit = (rest % 10) as u8;
+        rest /= 10;
+    }
+    digits
+}
+
+/// The number given by its decimal digits, most significant first, the inverse of [`decimal_digits_msb_first`]
+///
+/// Leading zeros are allowed, and no digits at all is 0. Returns `None` if a digit is above 9 or the number does not
+/// fit in a `u128`.
+///
+/// # Examples
+///
+/// ```
+/// use greek_number::decimal_digits_to_u128;
+///
+/// println!("{:?}", decimal_digits_to_u128(&[0, 0, 0, 9, 7, 5, 5, 4])); // Some(97554)
+/// println!("{:?}", decimal_digits_to_u128(&[2, 10])); // None
+/// ```
+pub fn decimal_digits_to_u128(digits: &[u8]) -> Option<u128> {
+    digits.iter().try_fold(0u128, |n, &digit| {
+        if digit > 9 {
+            return None;
+        }
+        n.checked_mul(10)?.checked_add(digit as u128)
+    })
 }
 
 #[cfg(test)]
 mod tests {
+    use alloc::borrow::Cow;
+    use alloc::string::{String, ToString};
+    use alloc::vec::Vec;
+
+    use super::annotate;
+    use super::checked_to_greek;
+    use super::decimal_digits_msb_first;
+    use super::decimal_digits_to_u128;
+    use super::from_greek;
+    use super::greek_fmt;
+    use super::greek_from_digits;
+    use super::greek_len;
+    use super::greek_power_of_ten;
+    use super::greek_range;
+    use super::pad_numerals;
     use super::to_greek;
+    use super::to_greek_both;
+    use super::to_greek_chars;
+    use super::to_greek_composite;
+    use super::to_greek_cow;
+    use super::to_greek_groups_str;
+    use super::to_greek_into;
+    use super::to_greek_lowercase;
+    use super::to_greek_nonzero;
+    use super::to_greek_ordinal;
+    use super::to_greek_padded;
+    use super::to_greek_parts;
+    use super::to_greek_signed;
+    use super::to_greek_title_case;
+    use super::to_greek_u128;
+    use super::to_greek_uppercase;
+    use super::try_to_greek;
+    use super::uses_myriad;
+    use super::write_greek;
+    use super::write_greek_lowercase;
+    use super::write_greek_uppercase;
     use super::Case;
+    use super::GreekNumberFormatter;
+    use super::KeraiaStyle;
+    use super::ToGreek;
+    use super::{to_greek_bytes, BufferTooSmall};
+    use super::{GreekNumberError, GREEK_MAX_DIGITS, GREEK_MAX_MYRIAD_POWER};
 
     macro_rules! greek_number_tests {
         ($($test_name:ident: $value:expr,)*) => {
@@ -192,6 +1029,16 @@ mod tests {
                     }
                 )*
             }
+
+            #[test]
+            fn greek_number_parse_test() {
+                $(
+                    {
+                        let (number, string, _) = $value;
+                        assert_eq!(from_greek(string), Ok(number), stringify!($test_name));
+                    }
+                )*
+            }
         }
     }
 
@@ -204,14 +1051,570 @@ mod tests {
 
         four_digit_5683_lower: (5683, "͵εχπγ", Case::Lower),
         four_digit_9184_lower: (9184, "͵θρπδ", Case::Lower),
-        four_digit_3398_lower: (3398, "͵γτϙη", Case::Lower),
+        four_digit_3398_lower: (3398, "͵γτϟη", Case::Lower),
         four_digit_1005_lower: (1005, "͵αε", Case::Lower),
 
+        koppa_90_lower: (90, "\u{03DF}\u{0374}", Case::Lower),
+        koppa_90_upper: (90, "\u{03DE}\u{0374}", Case::Upper),
+
         long_complex_0: (97_554, "αΜθʹ, ͵ζφνδ", Case::Lower),
         long_complex_1: (2_056_839_184, "βΜκʹ, αΜ͵εχπγ, ͵θρπδ", Case::Lower),
         long_complex_2: (12_312_398_676, "βΜρκγʹ, αΜ͵ασλθ, ͵ηχοϛ", Case::Lower),
 
         trailing_high_digit_0: (2_000_000_000, "βΜκʹ", Case::Lower),
         trailing_high_digit_1: (90_000_001, "αΜ͵θ, αʹ", Case::Lower),
+        zero_interior_group: (100_000_001, "βΜαʹ, αʹ", Case::Lower),
+    }
+
+    #[test]
+    fn try_to_greek_matches_to_greek_test() {
+        for n in [0, 1, 241, 5683, 97_554, 2_056_839_184] {
+            assert_eq!(
+                try_to_greek(n as u128, Case::Lower),
+                Ok(to_greek(n, Case::Lower))
+            );
+            assert_eq!(
+                try_to_greek(n as u128, Case::Upper),
+                Ok(to_greek(n, Case::Upper))
+            );
+        }
+        assert!(try_to_greek(u128::MAX, Case::Lower).is_ok());
+    }
+
+    #[test]
+    fn to_greek_u128_test() {
+        assert_eq!(to_greek_u128(10u128.pow(36), Case::Lower), "θΜαʹ");
+        assert_eq!(to_greek_u128(10u128.pow(38), Case::Upper), "θΜΡʹ");
+        assert_eq!(
+            to_greek_u128(u128::MAX, Case::Lower),
+            "θΜτμʹ, ηΜ͵βωκγ, ζΜ͵ϛχϟβ, ϛΜϡληʹ, εΜ͵δχλδ, δΜ͵ϛτλζ, γΜ͵δχζ, βΜ͵δτιζ, αΜ͵ϛωκα, ͵αυνε"
+        );
+    }
+
+    #[test]
+    fn to_greek_trait_test() {
+        assert_eq!(42u8.to_greek(Case::Lower), "μβʹ");
+        assert_eq!(u8::MAX.to_greek(Case::Lower), "σνεʹ");
+        assert_eq!(u16::MAX.to_greek(Case::Lower), "αΜϛʹ, ͵εφλε");
+        assert_eq!(u32::MAX.to_greek(Case::Upper), "βΜΜΒʹ, αΜ͵ΘΥϞϚ, ͵ΖΣϞΕ");
+        assert_eq!(
+            u64::MAX.to_greek(Case::Lower),
+            "δΜ͵αωμδ, γΜ͵ϛψμδ, βΜψλζʹ, αΜϡνεʹ, ͵αχιε"
+        );
+        assert_eq!(
+            u128::MAX.to_greek(Case::Lower),
+            to_greek_u128(u128::MAX, Case::Lower)
+        );
+        assert_eq!(
+            usize::MAX.to_greek(Case::Lower),
+            to_greek(usize::MAX, Case::Lower)
+        );
+        assert_eq!(241usize.to_greek(Case::Upper), "ΣΜΑʹ");
+    }
+
+    #[test]
+    fn to_greek_into_appends_test() {
+        let mut buf = String::from("Book ");
+        to_greek_into(2, Case::Upper, &mut buf);
+        assert_eq!(buf, "Book Βʹ");
+
+        buf.clear();
+        to_greek_into(1, Case::Lower, &mut buf);
+        to_greek_into(97_554, Case::Lower, &mut buf);
+        assert_eq!(buf, "αʹαΜθʹ, ͵ζφνδ");
+    }
+
+    #[test]
+    fn to_greek_title_case_test() {
+        assert_eq!(to_greek_title_case(1), "Αʹ");
+        assert_eq!(to_greek_title_case(241), "Σμαʹ");
+        assert_eq!(to_greek_title_case(5683), "͵Εχπγ");
+        assert_eq!(to_greek_title_case(97_554), "αΜΘʹ, ͵ζφνδ");
+        assert_eq!(to_greek_title_case(0), "𐆊");
+    }
+
+    #[test]
+    fn to_greek_chars_test() {
+        assert_eq!(to_greek_chars(1, Case::Lower), ['\u{03B1}', '\u{0374}']);
+        assert_eq!(to_greek_chars(1, Case::Upper), ['\u{0391}', '\u{0374}']);
+        assert_eq!(to_greek_chars(1000, Case::Lower), ['\u{0375}', '\u{03B1}']);
+        assert_eq!(to_greek_chars(0, Case::Lower), ['\u{1018A}']);
+    }
+
+    #[test]
+    fn greek_range_test() {
+        let range: Vec<String> = greek_range(1, 4, Case::Lower).collect();
+        assert_eq!(range, ["αʹ", "βʹ", "γʹ"]);
+        assert_eq!(
+            greek_range(9999, 10_001, Case::Upper).last().as_deref(),
+            Some("αΜΑʹ")
+        );
+        assert_eq!(greek_range(4, 4, Case::Lower).count(), 0);
+        assert_eq!(greek_range(4, 1, Case::Lower).count(), 0);
+        assert_eq!(
+            greek_range(usize::MAX - 1, usize::MAX, Case::Lower).count(),
+            1
+        );
+    }
+
+    #[test]
+    fn to_greek_cow_test() {
+        assert!(matches!(to_greek_cow(0, Case::Lower), Cow::Borrowed("𐆊")));
+        assert!(matches!(to_greek_cow(1, Case::Lower), Cow::Borrowed("αʹ")));
+        assert!(matches!(to_greek_cow(1, Case::Upper), Cow::Borrowed("Αʹ")));
+        assert!(matches!(to_greek_cow(241, Case::Lower), Cow::Owned(_)));
+        assert_eq!(to_greek_cow(241, Case::Lower), "σμαʹ");
+        for n in 0..100 {
+            for case in [Case::Lower, Case::Upper] {
+                assert_eq!(to_greek_cow(n, case), to_greek(n, case));
+            }
+        }
+    }
+
+    #[test]
+    fn to_greek_parts_test() {
+        assert_eq!(
+            to_greek_parts(241, Case::Lower),
+            (String::from("σμα"), Some('\u{0374}'))
+        );
+        assert_eq!(
+            to_greek_parts(1, Case::Upper),
+            (String::from("Α"), Some('\u{0374}'))
+        );
+        assert_eq!(
+            to_greek_parts(5683, Case::Lower),
+            (String::from("͵εχπγ"), None)
+        );
+        assert_eq!(
+            to_greek_parts(97_554, Case::Lower),
+            (String::from("αΜθʹ, ͵ζφνδ"), None)
+        );
+        assert_eq!(
+            to_greek_parts(2_000_000_000, Case::Lower),
+            (String::from("βΜκ"), Some('\u{0374}'))
+        );
+        assert_eq!(to_greek_parts(0, Case::Lower), (String::from("𐆊"), None));
+    }
+
+    #[cfg(feature = "unicode-segmentation")]
+    #[test]
+    fn to_greek_truncated_test() {
+        use super::to_greek_truncated;
+
+        assert_eq!(to_greek_truncated(97_554, Case::Lower, 6), "αΜθʹ,…");
+        assert_eq!(to_greek_truncated(97_554, Case::Upper, 11), "αΜΘʹ, ͵ΖΦΝΔ");
+        assert_eq!(to_greek_truncated(0, Case::Lower, 1), "𐆊");
+    }
+
+    #[cfg(feature = "unicode-width")]
+    #[test]
+    fn greek_display_width_test() {
+        use super::greek_display_width;
+
+        for n in [0, 1, 241, 5683, 97_554, usize::MAX] {
+            for case in [Case::Lower, Case::Upper] {
+                let width = greek_display_width(n, case);
+                assert_eq!(width, to_greek(n, case).chars().count(), "{}", n);
+            }
+        }
+    }
+
+    #[test]
+    fn to_greek_both_test() {
+        for n in [
+            0,
+            1,
+            6,
+            90,
+            241,
+            900,
+            5683,
+            97_554,
+            100_000_001,
+            2_056_839_184,
+            usize::MAX,
+        ]
+        .into_iter()
+        .chain((0..20_000).map(|i| i * 7919))
+        {
+            assert_eq!(
+                to_greek_both(n),
+                (to_greek(n, Case::Lower), to_greek(n, Case::Upper)),
+                "{}",
+                n
+            );
+        }
+    }
+
+    #[test]
+    fn to_greek_ordinal_test() {
+        assert_eq!(to_greek_ordinal(1, Case::Lower), "αʹος");
+        assert_eq!(to_greek_ordinal(2, Case::Upper), "Βʹος");
+        assert_eq!(to_greek_ordinal(241, Case::Lower), "σμαʹος");
+        assert_eq!(to_greek_ordinal(1000, Case::Lower), "͵αος");
+    }
+
+    #[test]
+    fn checked_to_greek_test() {
+        assert_eq!(checked_to_greek(241, Case::Lower).as_deref(), Some("σμαʹ"));
+        assert_eq!(
+            checked_to_greek(u128::MAX, Case::Lower),
+            Some(to_greek_u128(u128::MAX, Case::Lower))
+        );
+    }
+
+    #[test]
+    fn write_greek_matches_to_greek_test() {
+        for n in [0, 1, 241, 5683, 97_554, 100_000_001, usize::MAX] {
+            for case in [Case::Lower, Case::Upper] {
+                let mut buf = String::new();
+                write_greek(&mut buf, n, case).unwrap();
+                assert_eq!(buf, to_greek(n, case));
+            }
+        }
+    }
+
+    #[test]
+    fn greek_fmt_test() {
+        use super::SliceWriter;
+        use core::fmt::Write;
+
+        for n in [0, 1, 241, 5683, 97_554, usize::MAX] {
+            for case in [Case::Lower, Case::Upper] {
+                let mut buf = [0u8; 128];
+                let mut writer = SliceWriter {
+                    buf: &mut buf,
+                    len: 0,
+                };
+                write!(writer, "[{}]", greek_fmt(n, case)).unwrap();
+                let len = writer.len;
+                let written = core::str::from_utf8(&buf[..len]).unwrap();
+                assert_eq!(written, alloc::format!("[{}]", to_greek(n, case)));
+            }
+        }
+    }
+
+    #[test]
+    fn write_greek_case_test() {
+        for n in [0, 1, 241, 5683, 97_554, 100_000_001, usize::MAX] {
+            let mut lower = String::new();
+            write_greek_lowercase(&mut lower, n).unwrap();
+            assert_eq!(lower, to_greek_lowercase(n));
+            let mut upper = String::new();
+            write_greek_uppercase(&mut upper, n).unwrap();
+            assert_eq!(upper, to_greek_uppercase(n));
+        }
+    }
+
+    #[test]
+    fn to_greek_signed_test() {
+        assert_eq!(to_greek_signed(-241, Case::Lower), "-σμαʹ");
+        assert_eq!(to_greek_signed(0, Case::Lower), "𐆊");
+        assert_eq!(to_greek_signed(241, Case::Upper), "ΣΜΑʹ");
+    }
+
+    #[test]
+    fn greek_len_test() {
+        for n in (0..=20_000).chain([97_554, 100_000_001, usize::MAX]) {
+            for case in [Case::Lower, Case::Upper] {
+                assert_eq!(
+                    greek_len(n, case),
+                    to_greek(n, case).chars().count(),
+                    "{}",
+                    n
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn uses_myriad_test() {
+        assert!(!uses_myriad(0));
+        assert!(!uses_myriad(9999));
+        assert!(uses_myriad(10_000));
+        assert!(uses_myriad(99_999));
+        for n in [0, 9999, 10_000, 99_999, 100_000_000] {
+            assert_eq!(uses_myriad(n), to_greek(n, Case::Lower).contains('Μ'));
+        }
+    }
+
+    #[test]
+    fn greek_number_error_display_test() {
+        assert_eq!(
+            GreekNumberError::OutOfRange(u128::MAX).to_string(),
+            "340282366920938463463374607431768211455 is out of range for Greek numbers, which reach up to 10^40 - 1"
+        );
+        assert_eq!(
+            GreekNumberError::InvalidDigit(10).to_string(),
+            "10 is not a decimal digit"
+        );
+        assert_eq!(
+            GreekNumberError::TooManyDigits(41).to_string(),
+            "a number of 41 digits is out of range for Greek numbers, which reach up to 10^40 - 1"
+        );
+        assert_eq!(
+            GreekNumberError::AboveMax(10_000, 9999).to_string(),
+            "10000 is above the maximum of 9999 set for the formatter"
+        );
+        let cases = [
+            ("", "invalid Greek number: empty string at character 0"),
+            (
+                "σμαʹx",
+                "invalid Greek number: unexpected character at character 4",
+            ),
+            ("͵", "invalid Greek number: unexpected end at character 1"),
+            (
+                "ασʹ",
+                "invalid Greek number: digit or myriad group out of order at character 1",
+            ),
+            ("σμα", "invalid Greek number: missing keraia at character 3"),
+            (
+                "θΜαʹ",
+                "invalid Greek number: number too large at character 0",
+            ),
+        ];
+        for (s, message) in cases {
+            let error = GreekNumberError::from(from_greek(s).unwrap_err());
+            assert_eq!(error.to_string(), message, "{}", s);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn greek_number_error_question_mark_test() {
+        use std::error::Error;
+
+        fn parse_and_double(s: &str) -> Result<String, GreekNumberError> {
+            let n = from_greek(s)?;
+            try_to_greek(2 * n as u128, Case::Lower)
+        }
+        assert_eq!(parse_and_double("σμαʹ").as_deref(), Ok("υπβʹ"));
+        let error = parse_and_double("σμα").unwrap_err();
+        assert!(matches!(error, GreekNumberError::Parse(_)));
+        assert!(error.source().is_some());
+        assert!(GreekNumberError::OutOfRange(0).source().is_none());
+    }
+
+    #[test]
+    fn greek_from_digits_test() {
+        for n in [0, 1, 241, 9999, 10_000, 97_554, 100_000_001, u128::MAX] {
+            let digits: Vec<u8> = n.to_string().bytes().map(|b| b - b'0').collect();
+            for case in [Case::Lower, Case::Upper] {
+                assert_eq!(
+                    greek_from_digits(&digits, case),
+                    try_to_greek(n, case),
+                    "{}",
+                    n
+                );
+            }
+        }
+        assert_eq!(
+            greek_from_digits(&[], Case::Lower),
+            try_to_greek(0, Case::Lower)
+        );
+        assert_eq!(
+            greek_from_digits(&[0, 0, 2, 4, 1], Case::Lower).as_deref(),
+            Ok("σμαʹ")
+        );
+
+        // 10^40 - 1 does not fit in a u128 but still has a numeral
+        let greek = greek_from_digits(&[9; 40], Case::Lower).unwrap();
+        assert!(greek.starts_with("θΜ͵θϡϟθ, ηΜ͵θϡϟθ"));
+        assert!(greek.ends_with("αΜ͵θϡϟθ, ͵θϡϟθ"));
+        assert_eq!(
+            greek_from_digits(&[1; 41], Case::Lower),
+            Err(GreekNumberError::TooManyDigits(41))
+        );
+        assert_eq!(
+            greek_from_digits(&[2, 10, 1], Case::Lower),
+            Err(GreekNumberError::InvalidDigit(10))
+        );
+    }
+
+    #[test]
+    fn greek_power_of_ten_test() {
+        let lower = [
+            "αʹ", "ιʹ", "ρʹ", "͵α", "αΜαʹ", "αΜιʹ", "αΜρʹ", "αΜ͵α", "βΜαʹ",
+        ];
+        for (exp, expected) in (0..).zip(lower) {
+            assert_eq!(
+                greek_power_of_ten(exp, Case::Lower).as_deref(),
+                Ok(expected)
+            );
+        }
+        for exp in 0..=38 {
+            for case in [Case::Lower, Case::Upper] {
+                assert_eq!(
+                    greek_power_of_ten(exp, case),
+                    try_to_greek(10u128.pow(exp), case),
+                    "{}",
+                    exp
+                );
+            }
+        }
+        let mut digits = [0; 40];
+        digits[0] = 1;
+        assert_eq!(
+            greek_power_of_ten(39, Case::Lower),
+            greek_from_digits(&digits, Case::Lower)
+        );
+        assert_eq!(
+            greek_power_of_ten(40, Case::Lower),
+            Err(GreekNumberError::TooManyDigits(41))
+        );
+        assert_eq!(
+            greek_power_of_ten(u32::MAX, Case::Lower),
+            Err(GreekNumberError::TooManyDigits(
+                (u32::MAX as usize).saturating_add(1)
+            ))
+        );
+    }
+
+    #[test]
+    fn decimal_digits_test() {
+        assert_eq!(decimal_digits_msb_first(97_554), [0, 0, 0, 9, 7, 5, 5, 4]);
+        assert_eq!(decimal_digits_msb_first(1), [0, 0, 0, 1]);
+        assert_eq!(decimal_digits_msb_first(0), [0, 0, 0, 0]);
+        assert_eq!(decimal_digits_msb_first(9999), [9, 9, 9, 9]);
+        assert_eq!(decimal_digits_msb_first(10_000), [0, 0, 0, 1, 0, 0, 0, 0]);
+        assert_eq!(decimal_digits_msb_first(u128::MAX).len(), 40);
+
+        for n in [0, 1, 9999, 10_000, 97_554, 2_056_839_184, u128::MAX] {
+            let digits = decimal_digits_msb_first(n);
+            assert_eq!(digits.len() % 4, 0);
+            assert_eq!(decimal_digits_to_u128(&digits), Some(n));
+            assert_eq!(
+                greek_from_digits(&digits, Case::Lower),
+                try_to_greek(n, Case::Lower)
+            );
+        }
+        assert_eq!(decimal_digits_to_u128(&[]), Some(0));
+        assert_eq!(decimal_digits_to_u128(&[2, 10]), None);
+        assert_eq!(decimal_digits_to_u128(&[9; 40]), None);
+    }
+
+    #[test]
+    fn greek_max_test() {
+        assert_eq!(GREEK_MAX_MYRIAD_POWER, 9);
+        assert_eq!(GREEK_MAX_DIGITS, 40);
+        assert!(u128::MAX.ilog10() < GREEK_MAX_DIGITS);
+    }
+
+    #[test]
+    fn small_fast_path_test() {
+        for case in [Case::Lower, Case::Upper] {
+            let formatter = GreekNumberFormatter::new().case(case);
+            for n in 0..=9999 {
+                assert_eq!(to_greek(n, case), formatter.format(n), "{}", n);
+            }
+        }
+    }
+
+    #[test]
+    fn to_greek_nonzero_test() {
+        for n in [1, 6, 241, 9999, 97_554, 100_000_001, usize::MAX] {
+            let nonzero = core::num::NonZeroUsize::new(n).unwrap();
+            for case in [Case::Lower, Case::Upper] {
+                assert_eq!(to_greek_nonzero(nonzero, case), to_greek(n, case));
+            }
+        }
+    }
+
+    #[test]
+    fn to_greek_composite_test() {
+        assert_eq!(
+            to_greek_composite(&[2, 3, 241], Case::Upper, ", "),
+            "Βʹ, Γʹ, ΣΜΑʹ"
+        );
+        assert_eq!(
+            to_greek_composite(&[97_554, 0], Case::Lower, " / "),
+            "αΜθʹ, ͵ζφνδ / 𐆊"
+        );
+        assert_eq!(to_greek_composite(&[1], Case::Lower, "."), "αʹ");
+        assert_eq!(to_greek_composite(&[], Case::Lower, "."), "");
+    }
+
+    #[test]
+    fn to_greek_padded_test() {
+        let padded = to_greek_padded(&[1, 97_554, 241], Case::Lower);
+        assert_eq!(padded, ["         αʹ", "αΜθʹ, ͵ζφνδ", "       σμαʹ"]);
+        assert!(padded.iter().all(|s| s.chars().count() == 11));
+        assert!(to_greek_padded(&[], Case::Upper).is_empty());
+
+        // Combining overlines take no column, so they are not counted with the `unicode-width` feature.
+        let overlined = GreekNumberFormatter::new().keraia(KeraiaStyle::Overline);
+        let padded = pad_numerals(&overlined, &[1, 241]);
+        #[cfg(feature = "unicode-width")]
+        assert_eq!(padded, ["  α\u{0305}", "σ\u{0305}μ\u{0305}α\u{0305}"]);
+        #[cfg(not(feature = "unicode-width"))]
+        assert_eq!(padded, ["    α\u{0305}", "σ\u{0305}μ\u{0305}α\u{0305}"]);
+    }
+
+    #[test]
+    fn annotate_test() {
+        let glyphs = |pairs: &[(&str, usize)]| {
+            pairs
+                .iter()
+                .map(|&(glyph, value)| (String::from(glyph), value))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            annotate(241, Case::Lower),
+            glyphs(&[("σ", 200), ("μ", 40), ("α", 1)])
+        );
+        assert_eq!(
+            annotate(5683, Case::Upper),
+            glyphs(&[("͵Ε", 5000), ("Χ", 600), ("Π", 80), ("Γ", 3)])
+        );
+        assert_eq!(
+            annotate(97_554, Case::Lower),
+            glyphs(&[("θ", 90_000), ("͵ζ", 7000), ("φ", 500), ("ν", 50), ("δ", 4)])
+        );
+        assert_eq!(annotate(0, Case::Lower), glyphs(&[("𐆊", 0)]));
+        for n in [1, 241, 5683, 97_554, 2_056_839_184, usize::MAX] {
+            let glyphs = annotate(n, Case::Lower);
+            assert_eq!(glyphs.iter().map(|(_, value)| value).sum::<usize>(), n);
+            for (glyph, _) in &glyphs {
+                assert!(to_greek(n, Case::Lower).contains(glyph.as_str()));
+            }
+        }
+    }
+
+    #[test]
+    fn to_greek_groups_str_test() {
+        assert_eq!(
+            to_greek_groups_str(2_056_839_184, Case::Lower),
+            ["βΜκʹ", "αΜ͵εχπγ", "͵θρπδ"]
+        );
+        assert_eq!(
+            to_greek_groups_str(2_056_839_184, Case::Upper).join(", "),
+            to_greek(2_056_839_184, Case::Upper)
+        );
+        assert_eq!(
+            to_greek_groups_str(100_000_001, Case::Lower),
+            ["βΜαʹ", "αʹ"]
+        );
+        assert_eq!(to_greek_groups_str(241, Case::Lower), ["σμαʹ"]);
+        assert_eq!(to_greek_groups_str(0, Case::Lower), ["𐆊"]);
+    }
+
+    #[test]
+    fn to_greek_bytes_test() {
+        let mut buf = [0; 64];
+        assert_eq!(to_greek_bytes(241, Case::Lower, &mut buf), Ok("σμαʹ"));
+        assert_eq!(
+            to_greek_bytes(u32::MAX as usize, Case::Upper, &mut buf),
+            Ok("βΜΜΒʹ, αΜ͵ΘΥϞϚ, ͵ΖΣϞΕ")
+        );
+        assert_eq!(to_greek_bytes(0, Case::Lower, &mut buf), Ok("𐆊"));
+
+        let mut small = [0; 7];
+        assert_eq!(
+            to_greek_bytes(241, Case::Lower, &mut small),
+            Err(BufferTooSmall)
+        );
+        let mut exact = [0; 8];
+        assert_eq!(to_greek_bytes(241, Case::Lower, &mut exact), Ok("σμαʹ"));
+        assert_eq!(to_greek_bytes(1, Case::Lower, &mut []), Err(BufferTooSmall));
     }
 }
@@ -0,0 +1,464 @@
+use core::cmp::Ordering;
+use core::fmt;
+use core::str::FromStr;
+
+use crate::groups::{greek_groups, GreekGroup};
+use crate::parse::parse;
+use crate::{write_greek, Case, GreekParseError};
+
+/// A number that is displayed as Greek numbers
+///
+/// Formatting writes the numeral straight into the formatter, so `format!`, `write!` and `println!` do not build an
+/// intermediate `String`. The output is exactly that of [`to_greek_lowercase`](crate::to_greek_lowercase) or
+/// [`to_greek_uppercase`](crate::to_greek_uppercase).
+///
+/// # Examples
+///
+/// ```
+/// use greek_number::{Case, GreekNumber};
+///
+/// let greek = GreekNumber::new(241, Case::Lower);
+/// println!("{}", greek); // "σμαʹ"
+///
+/// let chapter = format!("Chapter {}", GreekNumber::new(241, Case::Upper));
+/// println!("{}", chapter); // "Chapter ΣΜΑʹ"
+/// ```
+///
+/// The `Debug` output also shows how the number splits into myriad groups, as `(M^power, thousands,hundreds,tens,ones)`:
+///
+/// ```
+/// use greek_number::{Case, GreekNumber};
+///
+/// let greek = GreekNumber::new(97_554, Case::Lower);
+/// println!("{:?}", greek);
+/// // GreekNumber { value: 97554, case: Lower, groups: [(M^1, 0,0,0,9), (M^0, 7,5,5,4)] }
+/// ```
+///
+/// Comparisons are by value only: the case is how the number is written, not part of it, so `ΣΜΑʹ` and `σμαʹ` are
+/// equal and numbers sort in numeric order in a `Vec` or a `BTreeSet`.
+#[derive(Clone, Copy)]
+pub struct GreekNumber {
+    value: usize,
+    case: Case,
+}
+
+impl GreekNumber {
+    /// Wrap a number to be displayed in the given case.
+    pub fn new(value: usize, case: Case) -> Self {
+        GreekNumber { value, case }
+    }
+
+    /// The wrapped number.
+    pub fn value(&self) -> usize {
+        self.value
+    }
+
+    /// The case the number is displayed in.
+    pub fn case(&self) -> Case {
+        self.case
+    }
+}
+
+/// The myriad groups of a number, debug formatted as a list.
+struct Groups(usize);
+
+impl fmt::Debug for Groups {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(greek_groups(self.0).map(Group))
+            .finish()
+    }
+}
+
+/// One myriad group, debug formatted as `(M^power, thousands,hundreds,tens,ones)`.
+struct Group(GreekGroup);
+
+impl fmt::Debug for Group {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let group = self.0;
+        write!(
+            f,
+            "(M^{}, {},{},{},{})",
+            group.power(),
+            group.thousands(),
+            group.hundreds(),
+            group.tens(),
+            group.ones()
+        )
+    }
+}
+
+impl fmt::Debug for GreekNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GreekNumber")
+            .field("value", &self.value)
+            .field("case", &self.case)
+            .field("groups", &Groups(self.value))
+            .finish()
+    }
+}
+
+impl fmt::Display for GreekNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_greek(f, self.value, self.case)
+    }
+}
+
+/// Wraps a number to be displayed in lowercase, as [`to_greek_lowercase`](crate::to_greek_lowercase) writes it.
+///
+/// # Examples
+///
+/// ```
+/// use greek_number::GreekNumber;
+///
+/// let chapters: Vec<String> = (1..=3usize).map(GreekNumber::from).map(|n| n.to_string()).collect();
+/// println!("{:?}", chapters); // ["αʹ", "βʹ", "γʹ"]
+/// ```
+impl From<usize> for GreekNumber {
+    fn from(value: usize) -> Self {
+        GreekNumber::new(value, Case::Lower)
+    }
+}
+
+// Only the integers that always fit in a `usize`.
+macro_rules! impl_from_unsigned {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for GreekNumber {
+                fn from(value: $t) -> Self {
+                    GreekNumber::from(usize::from(value))
+                }
+            }
+        )*
+    };
+}
+
+impl_from_unsigned!(u8, u16);
+
+/// Compares the wrapped number, whatever the case.
+///
+/// # Examples
+///
+/// ```
+/// use greek_number::{Case, GreekNumber};
+///
+/// println!("{}", GreekNumber::new(241, Case::Upper) == 241); // true
+/// println!("{}", GreekNumber::new(241, Case::Lower) < 1000); // true
+/// ```
+impl PartialEq<usize> for GreekNumber {
+    fn eq(&self, other: &usize) -> bool {
+        self.value == *other
+    }
+}
+
+impl PartialEq<GreekNumber> for usize {
+    fn eq(&self, other: &GreekNumber) -> bool {
+        *self == other.value
+    }
+}
+
+/// Compares the wrapped numbers, whatever their case.
+impl PartialEq for GreekNumber {
+    fn eq(&self, other: &GreekNumber) -> bool {
+        self.value == other.value
+    }
+}
+
+impl Eq for GreekNumber {}
+
+/// Orders by the wrapped numbers, whatever their case.
+impl Ord for GreekNumber {
+    fn cmp(&self, other: &GreekNumber) -> Ordering {
+        self.value.cmp(&other.value)
+    }
+}
+
+impl PartialOrd for GreekNumber {
+    fn partial_cmp(&self, other: &GreekNumber) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Compares the wrapped number, whatever the case.
+impl PartialOrd<usize> for GreekNumber {
+    fn partial_cmp(&self, other: &usize) -> Option<Ordering> {
+        self.value.partial_cmp(other)
+    }
+}
+
+impl PartialOrd<GreekNumber> for usize {
+    fn partial_cmp(&self, other: &GreekNumber) -> Option<Ordering> {
+        self.partial_cmp(&other.value)
+    }
+}
+
+/// Parses with [`from_greek`](crate::from_greek), keeping the case of the letters (lowercase for the zero sign).
+///
+/// # Examples
+///
+/// ```
+/// use greek_number::GreekNumber;
+///
+/// let greek: GreekNumber = "ΣΜΑʹ".parse().unwrap();
+/// println!("{:?}", (greek.value(), greek.case())); // (241, Upper)
+/// ```
+impl FromStr for GreekNumber {
+    type Err = GreekParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (value, case) = parse(s)?;
+        Ok(GreekNumber::new(value, case.unwrap_or(Case::Lower)))
+    }
+}
+
+/// Parses like [`FromStr`], for APIs that take `TryFrom` conversions.
+///
+/// # Examples
+///
+/// ```
+/// use greek_number::GreekNumber;
+///
+/// let greek = GreekNumber::try_from("σμαʹ").unwrap();
+/// println!("{}", greek.value()); // 241
+/// ```
+impl TryFrom<&str> for GreekNumber {
+    type Error = GreekParseError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+/// Serializes as the rendered numeral string, e.g. `"σμαʹ"`.
+#[cfg(feature = "serde")]
+impl serde::Serialize for GreekNumber {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// Deserializes by parsing the numeral string with [`from_greek`](crate::from_greek), keeping the case of its letters.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for GreekNumber {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct GreekNumberVisitor;
+
+        impl serde::de::Visitor<'_> for GreekNumberVisitor {
+            type Value = GreekNumber;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a Greek number")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<GreekNumber, E> {
+                v.parse()
+                    .map_err(|_| E::invalid_value(serde::de::Unexpected::Str(v), &self))
+            }
+        }
+
+        deserializer.deserialize_str(GreekNumberVisitor)
+    }
+}
+
+/// Generates any `usize`, all of which are in range, in either case; for fuzzing the `from_greek`/`to_greek` round
+/// trip.
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for GreekNumber {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let value = u.arbitrary()?;
+        let case = if u.arbitrary()? {
+            Case::Upper
+        } else {
+            Case::Lower
+        };
+        Ok(GreekNumber::new(value, case))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        arbitrary::size_hint::and(
+            <usize as arbitrary::Arbitrary>::size_hint(depth),
+            <bool as arbitrary::Arbitrary>::size_hint(depth),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::collections::BTreeSet;
+    use alloc::format;
+    use alloc::string::ToString;
+    use alloc::vec::Vec;
+    use core::cmp::Ordering;
+
+    use super::GreekNumber;
+    use crate::{to_greek_lowercase, to_greek_uppercase, Case, GreekParseErrorKind};
+
+    #[test]
+    fn display_matches_to_greek_test() {
+        for n in [
+            0,
+            1,
+            6,
+            90,
+            241,
+            5683,
+            97_554,
+            90_000_001,
+            2_056_839_184,
+            usize::MAX,
+        ] {
+            assert_eq!(
+                GreekNumber::new(n, Case::Lower).to_string(),
+                to_greek_lowercase(n)
+            );
+            assert_eq!(
+                GreekNumber::new(n, Case::Upper).to_string(),
+                to_greek_uppercase(n)
+            );
+        }
+        assert_eq!(
+            format!("[{}]", GreekNumber::new(241, Case::Lower)),
+            "[σμαʹ]"
+        );
+    }
+
+    #[test]
+    fn debug_shows_groups_test() {
+        assert_eq!(
+            format!("{:?}", GreekNumber::new(97_554, Case::Lower)),
+            "GreekNumber { value: 97554, case: Lower, groups: [(M^1, 0,0,0,9), (M^0, 7,5,5,4)] }"
+        );
+        assert_eq!(
+            format!("{:?}", GreekNumber::new(100_000_001, Case::Upper)),
+            "GreekNumber { value: 100000001, case: Upper, groups: [(M^2, 0,0,0,1), (M^0, 0,0,0,1)] }"
+        );
+        assert_eq!(
+            format!("{:?}", GreekNumber::new(0, Case::Lower)),
+            "GreekNumber { value: 0, case: Lower, groups: [] }"
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip_test() {
+        for n in [0, 1, 241, 5683, 97_554, 2_056_839_184, usize::MAX] {
+            for case in [Case::Lower, Case::Upper] {
+                let greek = GreekNumber::new(n, case);
+                let json = serde_json::to_string(&greek).unwrap();
+                assert_eq!(json, format!("\"{}\"", greek));
+                let parsed: GreekNumber = serde_json::from_str(&json).unwrap();
+                assert_eq!(parsed, greek);
+                if n != 0 {
+                    assert_eq!(parsed.case(), case);
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_invalid_test() {
+        for json in ["\"\"", "\"ασʹ\"", "\"σμα\"", "241"] {
+            assert!(
+                serde_json::from_str::<GreekNumber>(json).is_err(),
+                "{}",
+                json
+            );
+        }
+    }
+
+    #[test]
+    fn from_str_test() {
+        let parse = |s: &str| s.parse::<GreekNumber>().map(|g| (g.value(), g.case()));
+        assert_eq!(parse("σμαʹ"), Ok((241, Case::Lower)));
+        assert_eq!(parse("ΣΜΑʹ"), Ok((241, Case::Upper)));
+        assert_eq!(parse("𐆊"), Ok((0, Case::Lower)));
+        assert_eq!(
+            "σασʹ".parse::<GreekNumber>().unwrap_err().kind(),
+            GreekParseErrorKind::OutOfOrder
+        );
+    }
+
+    #[test]
+    fn compare_with_usize_test() {
+        let greek = GreekNumber::new(241, Case::Lower);
+        assert!(greek == 241);
+        assert!(greek != 240);
+        assert!(241 == greek);
+        assert_eq!(GreekNumber::new(241, Case::Upper), 241);
+
+        assert!(greek < 242);
+        assert!(greek > 240);
+        assert!(greek <= 241);
+        assert!(greek >= 241);
+        assert!(240 < greek);
+        assert_eq!(greek.partial_cmp(&241), Some(core::cmp::Ordering::Equal));
+        assert!(GreekNumber::new(0, Case::Lower) < usize::MAX);
+    }
+
+    #[test]
+    fn ord_ignores_case_test() {
+        let lower = GreekNumber::new(241, Case::Lower);
+        let upper = GreekNumber::new(241, Case::Upper);
+        assert_eq!(lower, upper);
+        assert_eq!(lower.cmp(&upper), Ordering::Equal);
+        assert!(GreekNumber::new(240, Case::Upper) < lower);
+
+        let set: BTreeSet<GreekNumber> = [97_554, 1, 241, 5683, 0]
+            .into_iter()
+            .zip([Case::Lower, Case::Upper].into_iter().cycle())
+            .map(|(n, case)| GreekNumber::new(n, case))
+            .chain([upper])
+            .collect();
+        let values: Vec<usize> = set.iter().map(|g| g.value()).collect();
+        assert_eq!(values, [0, 1, 241, 5683, 97_554]);
+        assert!(set.contains(&lower));
+
+        let mut numbers = [upper, GreekNumber::new(1, Case::Upper), lower];
+        numbers.sort();
+        assert_eq!(numbers.map(|g| g.value()), [1, 241, 241]);
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn arbitrary_round_trip_test() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let bytes: [u8; 64] = core::array::from_fn(|i| (i * 37 + 11) as u8);
+        let mut u = Unstructured::new(&bytes);
+        let mut cases = [false; 2];
+        while let Ok(greek) = GreekNumber::arbitrary(&mut u) {
+            if u.is_empty() {
+                break;
+            }
+            let parsed: GreekNumber = greek.to_string().parse().unwrap();
+            assert_eq!(parsed.value(), greek.value());
+            cases[(greek.case() == Case::Upper) as usize] = true;
+        }
+        assert_eq!(cases, [true, true]);
+    }
+
+    #[test]
+    fn try_from_str_test() {
+        let greek = GreekNumber::try_from("ΣΜΑʹ").unwrap();
+        assert_eq!((greek.value(), greek.case()), (241, Case::Upper));
+        assert_eq!(GreekNumber::try_from("σμαʹ"), "σμαʹ".parse());
+        assert_eq!(
+            GreekNumber::try_from("σμα").unwrap_err().kind(),
+            GreekParseErrorKind::MissingKeraia
+        );
+    }
+
+    #[test]
+    fn from_integer_test() {
+        assert_eq!(
+            GreekNumber::from(241usize),
+            GreekNumber::new(241, Case::Lower)
+        );
+        assert_eq!(GreekNumber::from(241u8).to_string(), "σμαʹ");
+        assert_eq!(GreekNumber::from(9999u16).to_string(), "͵θϡϟθ");
+        let greek: GreekNumber = 97_554usize.into();
+        assert_eq!(greek.to_string(), to_greek_lowercase(97_554));
+        assert_eq!(greek.case(), Case::Lower);
+    }
+}
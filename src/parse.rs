@@ -0,0 +1,1179 @@
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt;
+use core::ops::Range;
+
+use crate::tables::{HUNDREDS, ONES, TENS, THOUSANDS_SIGN};
+use crate::{Case, SixStyle};
+
+/// Parse a Greek number back into an integer
+///
+/// This is the inverse of [`to_greek_lowercase`](crate::to_greek_lowercase) and
+/// [`to_greek_uppercase`](crate::to_greek_uppercase). The letters may be either all lowercase or all uppercase, and
+/// both keraia code points, U+0374 GREEK NUMERAL SIGN and U+02B9 MODIFIER LETTER PRIME, are accepted. The Greek Zero
+/// Sign `𐆊` parses as 0, as do the other zeros [`ZeroStyle`](crate::ZeroStyle) writes: the omicron with a macron `ο̄`
+/// and the ASCII digit `0` on its own, e.g. `"0"`; no other Latin digit is read. Each may be followed by a keraia or
+/// an overline, as [`zero_keraia`](crate::GreekNumberFormatter::zero_keraia) writes it. The final sigma `ς` is read as
+/// the sigma `σ` for 200, although the stringifier always emits `σ`.
+///
+/// Myriad groups may be separated by a comma (the stringifier emits `", "` by default), a middle dot (U+00B7) or an ano
+/// teleia (U+0387), with or without whitespace around it, by whitespace alone, or by nothing at all. Without a
+/// separator a group ends where the next one has to start, e.g. at a keraia or a second thousands sign; note that
+/// without a separator `αΜ͵θ` followed by `αʹ` reads as a single group, so such output does not round trip. The
+/// thousands sign may also come before the myriad prefix of its group, as in `͵αΜεχπγ` for 5683 × 10000.
+///
+/// Whitespace and stray combining overlines (U+0305) before and after the numeral are ignored. Within it, an overline
+/// may follow each digit letter, as [`KeraiaStyle::Overline`](crate::KeraiaStyle::Overline) writes them, and a group with
+/// overlined letters needs no keraia. Any other character in the middle is an error.
+///
+/// Returns a [`GreekParseError`] saying what is wrong and where if the string is not a well-formed Greek number or if
+/// its value does not fit in a `usize`.
+///
+/// # Examples
+///
+/// ```
+/// use greek_number::from_greek;
+///
+/// let number = from_greek("αʹ");
+/// println!("{:?}", number); // Ok(1)
+///
+/// let number = from_greek("σμαʹ");
+/// println!("{:?}", number); // Ok(241)
+///
+/// let number = from_greek("αΜθʹ, ͵ζφνδ");
+/// println!("{:?}", number); // Ok(97554)
+///
+/// let error = from_greek("σασʹ").unwrap_err();
+/// println!("{}", error.char_index()); // 2
+/// ```
+pub fn from_greek(s: &str) -> Result<usize, GreekParseError> {
+    parse(s).map(|(value, _)| value)
+}
+
+/// Parse a Greek number into a `usize`
+///
+/// This is [`from_greek`] under a name that says what it returns, for callers who want the number rather than a
+/// [`GreekNumber`](crate::GreekNumber); `GreekNumber` itself can be parsed with `TryFrom<&str>` or `str::parse`.
+///
+/// # Examples
+///
+/// ```
+/// use greek_number::greek_to_usize;
+///
+/// let number = greek_to_usize("σμαʹ");
+/// println!("{:?}", number); // Ok(241)
+/// ```
+pub fn greek_to_usize(s: &str) -> Result<usize, GreekParseError> {
+    from_greek(s)
+}
+
+/// Parse a Greek number back into an integer, allowing letters of both cases
+///
+/// This is [`from_greek`] without the rule that all letters share one case, for numerals copied out of running text,
+/// e.g. `Σμαʹ` at the start of a sentence. The myriad prefix may be uppercase too, except after a thousands sign:
+/// `͵ΑΜΕ` is 1045, not a thousands sign written before the prefix `ΑΜ`.
+///
+/// # Examples
+///
+/// ```
+/// use greek_number::from_greek_ignore_case;
+///
+/// let number = from_greek_ignore_case("Σμαʹ");
+/// println!("{:?}", number); // Ok(241)
+///
+/// let number = from_greek_ignore_case("ΑΜΘʹ, ͵Ζφνδ");
+/// println!("{:?}", number); // Ok(97554)
+/// ```
+pub fn from_greek_ignore_case(s: &str) -> Result<usize, GreekParseError> {
+    parse_with(s, true, SixStyle::Stigma, true).map(|(value, _)| value)
+}
+
+/// Parse a Greek number back into an integer, given the letter its writer used for 6
+///
+/// With [`SixStyle::SigmaTau`], `στ` (or `ΣΤ`) wherever a 6 can go is read as 6 rather than as 200 and 300, which could
+/// never follow each other anyway, so `στʹ` is 6 and `σστʹ` is 206. The stigma and the digamma are always accepted, so
+/// the other styles parse just like [`from_greek`].
+///
+/// # Examples
+///
+/// ```
+/// use greek_number::{from_greek_with, SixStyle};
+///
+/// let number = from_greek_with(SixStyle::SigmaTau, "στʹ");
+/// println!("{:?}", number); // Ok(6)
+/// ```
+pub fn from_greek_with(six: SixStyle, s: &str) -> Result<usize, GreekParseError> {
+    parse_with(s, false, six, true).map(|(value, _)| value)
+}
+
+/// Parse a Greek number back into an integer, without requiring the keraia
+///
+/// This is [`from_greek`] for transcriptions that leave the keraia out, as
+/// [`KeraiaStyle::None`](crate::KeraiaStyle::None) writes them: `σμα` is 241 as well as `σμαʹ`. Digit letters out of
+/// order are still an error, so `ασ` is rejected rather than read as two numbers.
+///
+/// # Examples
+///
+/// ```
+/// use greek_number::from_greek_lenient;
+///
+/// let number = from_greek_lenient("σμα");
+/// println!("{:?}", number); // Ok(241)
+///
+/// let number = from_greek_lenient("αΜθ, ͵ζφνδ");
+/// println!("{:?}", number); // Ok(97554)
+/// ```
+pub fn from_greek_lenient(s: &str) -> Result<usize, GreekParseError> {
+    parse_with(s, false, SixStyle::Stigma, false).map(|(value, _)| value)
+}
+
+/// Find the case a Greek number is written in
+///
+/// Returns the case of the letters if they all share one, and `None` if they are mixed or if there are no cased
+/// letters, as for the zero sign. The myriad prefix is always lowercase, in either case, so it is not counted. The
+/// string does not need to be a well-formed number; use [`from_greek`] to check that. Together they let a numeral be
+/// decoded and written again in its original case.
+///
+/// # Examples
+///
+/// ```
+/// use greek_number::detect_case;
+///
+/// println!("{:?}", detect_case("ΣΜΑʹ")); // Some(Upper)
+/// println!("{:?}", detect_case("αΜθʹ, ͵ζφνδ")); // Some(Lower)
+/// println!("{:?}", detect_case("Σμαʹ")); // None
+/// ```
+pub fn detect_case(s: &str) -> Option<Case> {
+    let mut case = None;
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c.is_lowercase() && chars.peek() == Some(&MYRIAD) {
+            // A myriad prefix, and the myriad sign after it.
+            chars.next();
+            continue;
+        }
+        let letter_case = if c.is_lowercase() {
+            Case::Lower
+        } else if c.is_uppercase() {
+            Case::Upper
+        } else {
+            continue;
+        };
+        if *case.get_or_insert(letter_case) != letter_case {
+            return None;
+        }
+    }
+    case
+}
+
+/// Check whether a string is a well-formed Greek number
+///
+/// Returns `true` exactly for the strings that [`from_greek`] accepts: letters in thousands-hundreds-tens-ones order,
+/// a single keraia closing each group that has no thousands sign, and myriad groups with decreasing powers.
+///
+/// # Examples
+///
+/// ```
+/// use greek_number::is_valid_greek_number;
+///
+/// println!("{}", is_valid_greek_number("σμαʹ")); // true
+/// println!("{}", is_valid_greek_number("ασʹ")); // false
+/// ```
+pub fn is_valid_greek_number(s: &str) -> bool {
+    from_greek(s).is_ok()
+}
+
+/// What is wrong with a string that is not a Greek number
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GreekParseErrorKind {
+    /// The string is empty.
+    Empty,
+    /// A character that cannot appear at this point of a Greek number.
+    UnexpectedChar,
+    /// The string ends where more letters are needed, e.g. after a thousands sign or a separator.
+    UnexpectedEnd,
+    /// A digit letter or a myriad group in the wrong place: digits must go thousands, hundreds, tens, ones and myriad
+    /// powers must decrease.
+    OutOfOrder,
+    /// A second digit letter for the same place of a group, as in `ααʹ` or `ρρʹ`.
+    RepeatedDigit,
+    /// A group without a thousands sign is not closed by a keraia.
+    MissingKeraia,
+    /// The value does not fit in a `usize`.
+    TooLarge,
+}
+
+/// The error returned by [`from_greek`] for a string that is not a Greek number
+///
+/// It tells what went wrong and at which character, counted in `char`s from the start of the string, so the offending
+/// character can be pointed out to the user.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GreekParseError {
+    kind: GreekParseErrorKind,
+    char_index: usize,
+}
+
+impl GreekParseError {
+    fn new(kind: GreekParseErrorKind, char_index: usize) -> Self {
+        GreekParseError { kind, char_index }
+    }
+
+    /// What is wrong with the string.
+    pub fn kind(&self) -> GreekParseErrorKind {
+        self.kind
+    }
+
+    /// The index, in `char`s, of the character where parsing failed. It is the length of the string if it ended too
+    /// early.
+    pub fn char_index(&self) -> usize {
+        self.char_index
+    }
+}
+
+impl fmt::Display for GreekParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let description = match self.kind {
+            GreekParseErrorKind::Empty => "empty string",
+            GreekParseErrorKind::UnexpectedChar => "unexpected character",
+            GreekParseErrorKind::UnexpectedEnd => "unexpected end",
+            GreekParseErrorKind::OutOfOrder => "digit or myriad group out of order",
+            GreekParseErrorKind::RepeatedDigit => "repeated digit",
+            GreekParseErrorKind::MissingKeraia => "missing keraia",
+            GreekParseErrorKind::TooLarge => "number too large",
+        };
+        write!(f, "{} at character {}", description, self.char_index)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for GreekParseError {}
+
+/// Parse a Greek number, also returning the case of its letters (`None` for the zero sign).
+pub(crate) fn parse(s: &str) -> Result<(usize, Option<Case>), GreekParseError> {
+    parse_with(s, false, SixStyle::Stigma, true)
+}
+
+/// Parse a Greek number, also returning the case of its first letter (`None` for the zero sign).
+fn parse_with(
+    s: &str,
+    ignore_case: bool,
+    six: SixStyle,
+    require_keraia: bool,
+) -> Result<(usize, Option<Case>), GreekParseError> {
+    let chars: Vec<char> = s.chars().collect();
+    // Leave out the ignorable characters at either end, but keep counting positions from the start of `s`.
+    let start = chars
+        .iter()
+        .position(|&c| !is_ignorable_at_end(c))
+        .unwrap_or(chars.len());
+    let mut end = chars.len();
+    while end > start {
+        // An overline right after a digit letter is part of the numeral.
+        let overlines_digit = end >= 2 && is_digit_letter(chars[end - 2]);
+        match chars[end - 1] {
+            OVERLINE if overlines_digit => break,
+            c if is_ignorable_at_end(c) => end -= 1,
+            _ => break,
+        }
+    }
+    match &chars[start..end] {
+        [] => return Err(GreekParseError::new(GreekParseErrorKind::Empty, start)),
+        [ZERO] | ['0'] => return Ok((0, None)),
+        ['ο', MACRON] => return Ok((0, Some(Case::Lower))),
+        ['Ο', MACRON] => return Ok((0, Some(Case::Upper))),
+        // A zero may be marked by a keraia, as `zero_keraia` writes it; an overline after it is trimmed above.
+        [ZERO, k] | ['0', k] if is_keraia(*k) => return Ok((0, None)),
+        ['ο', MACRON, k] if is_keraia(*k) => return Ok((0, Some(Case::Lower))),
+        ['Ο', MACRON, k] if is_keraia(*k) => return Ok((0, Some(Case::Upper))),
+        [ZERO, ..] => {
+            return Err(GreekParseError::new(
+                GreekParseErrorKind::UnexpectedChar,
+                start + 1,
+            ));
+        }
+        _ => {}
+    }
+    let mut parser = Parser {
+        chars: &chars[..end],
+        pos: start,
+        case: None,
+        ignore_case,
+        sigma_tau: six == SixStyle::SigmaTau,
+        require_keraia,
+        overlined: false,
+    };
+    let value = parser.number()?;
+    Ok((value, parser.case))
+}
+
+const ZERO: char = '𐆊';
+/// U+0304 COMBINING MACRON, over the omicron of [`ZeroStyle::Omicron`](crate::ZeroStyle::Omicron).
+const MACRON: char = '\u{0304}';
+const MYRIAD: char = 'Μ';
+// The punctuation that may separate myriad groups, with any whitespace around it.
+const GROUP_SEPARATORS: [char; 3] = [',', '\u{00B7}', '\u{0387}'];
+
+/// U+0305 COMBINING OVERLINE, as written by [`KeraiaStyle::Overline`](crate::KeraiaStyle::Overline).
+const OVERLINE: char = '\u{0305}';
+
+/// Whether a character is skipped before and after the numeral: whitespace and stray overlines.
+fn is_ignorable_at_end(c: char) -> bool {
+    c.is_whitespace() || c == OVERLINE
+}
+
+fn is_keraia(c: char) -> bool {
+    c == '\u{02B9}' || c == '\u{0374}'
+}
+
+/// Map alternative letterforms that we accept but never emit to the glyph in the digit tables.
+fn canonical(c: char) -> char {
+    match c {
+        // archaic koppa
+        'ϙ' => 'ϟ',
+        'Ϙ' => 'Ϟ',
+        // digamma for 6
+        'ϝ' => 'ϛ',
+        'Ϝ' => 'Ϛ',
+        // archaic sampi for 900
+        'ͳ' => 'ϡ',
+        'Ͳ' => 'Ϡ',
+        // final sigma for 200, common in numerals copied out of running text
+        'ς' => 'σ',
+        c => c,
+    }
+}
+
+/// Find the digit and case of a letter in one of the digit tables.
+fn lookup(table: &[[&str; 2]; 9], c: char) -> Option<(usize, Case)> {
+    let c = canonical(c);
+    table.iter().enumerate().find_map(|(i, glyphs)| {
+        if glyphs[0].starts_with(c) {
+            Some((i + 1, Case::Lower))
+        } else if glyphs[1].starts_with(c) {
+            Some((i + 1, Case::Upper))
+        } else {
+            None
+        }
+    })
+}
+
+/// Whether a character is a digit letter of any position.
+fn is_digit_letter(c: char) -> bool {
+    letter_value(c).is_some()
+}
+
+/// The place of a digit letter's value: 1, 10 or 100.
+fn place_of(value: usize) -> usize {
+    match value {
+        100.. => 100,
+        10.. => 10,
+        _ => 1,
+    }
+}
+
+/// Compare two Greek numbers by their value
+///
+/// Both strings are parsed with [`from_greek`]; returns `None` if either is not a valid Greek number. Use it to sort
+/// numerals, which sort wrongly as plain strings.
+///
+/// # Examples
+///
+/// ```
+/// use greek_number::greek_cmp;
+///
+/// let mut numerals = vec!["σμαʹ", "αʹ", "ιʹ"];
+/// numerals.sort_by(|a, b| greek_cmp(a, b).unwrap());
+/// println!("{:?}", numerals); // ["αʹ", "ιʹ", "σμαʹ"]
+/// ```
+pub fn greek_cmp(a: &str, b: &str) -> Option<Ordering> {
+    Some(from_greek(a).ok()?.cmp(&from_greek(b).ok()?))
+}
+
+/// Find and parse every Greek number in a text
+///
+/// Returns the byte range of each numeral in `text` with its value, in order. A numeral is a run of digit letters,
+/// myriad signs, thousands signs and keraiai that [`from_greek`] accepts as a whole and that is not part of a longer
+/// word; groups of one numeral may be separated as `from_greek` allows, and the longest numeral starting at a word is
+/// taken. Plain words made of digit letters, like `και`, are not numerals since they do not parse.
+///
+/// # Examples
+///
+/// ```
+/// use greek_number::parse_all;
+///
+/// let text = "Κεφάλαιον σμαʹ, στίχος αΜθʹ, ͵ζφνδ.";
+/// for (range, value) in parse_all(text) {
+///     println!("{:?} {}", &text[range], value);
+/// }
+/// // "σμαʹ" 241
+/// // "αΜθʹ, ͵ζφνδ" 97554
+/// ```
+pub fn parse_all(text: &str) -> Vec<(Range<usize>, usize)> {
+    let mut numerals = Vec::new();
+    let mut start = 0;
+    while let Some(c) = text[start..].chars().next() {
+        let at_word_start = !text[..start].ends_with(char::is_alphanumeric);
+        if at_word_start && is_numeral_char(c) {
+            if let Some((len, value)) = longest_numeral(&text[start..]) {
+                numerals.push((start..start + len, value));
+                start += len;
+                continue;
+            }
+        }
+        start += c.len_utf8();
+    }
+    numerals
+}
+
+/// Whether a character may be part of a numeral in running text.
+fn is_numeral_char(c: char) -> bool {
+    is_digit_letter(c) || is_keraia(c) || [ZERO, MYRIAD, THOUSANDS_SIGN, OVERLINE].contains(&c)
+}
+
+/// The length and value of the longest numeral at the start of `s` that ends at a word boundary.
+fn longest_numeral(s: &str) -> Option<(usize, usize)> {
+    let mut longest = None;
+    let mut end = 0;
+    loop {
+        // the end of this run of numeral characters
+        end += s[end..]
+            .find(|c| !is_numeral_char(c))
+            .unwrap_or(s.len() - end);
+        let at_word_end = !s[end..].starts_with(char::is_alphanumeric);
+        if at_word_end {
+            if let Ok(value) = from_greek(&s[..end]) {
+                longest = Some((end, value));
+            }
+        }
+        // try to go on with the next group after a separator
+        let separator = separator_len(&s[end..]);
+        if separator == 0 || !s[end + separator..].starts_with(is_numeral_char) {
+            return longest;
+        }
+        end += separator;
+    }
+}
+
+/// The byte length of a separator between myriad groups at the start of `s`, as [`Parser::separator`] skips it.
+fn separator_len(s: &str) -> usize {
+    let whitespace = |s: &str| s.len() - s.trim_start().len();
+    let mut len = whitespace(s);
+    if let Some(c) = s[len..]
+        .chars()
+        .next()
+        .filter(|c| GROUP_SEPARATORS.contains(c))
+    {
+        len += c.len_utf8();
+        len += whitespace(&s[len..]);
+    }
+    len
+}
+
+/// The value of a single Greek letter as a numeral
+///
+/// Both cases are accepted, as are the alternative forms the parser reads: the archaic koppa `ϙ`, the digamma `ϝ`, the
+/// archaic sampi `ͳ` and the final sigma `ς`. Returns `None` for any other character, such as Latin letters and the
+/// keraia or thousands sign.
+///
+/// # Examples
+///
+/// ```
+/// use greek_number::letter_value;
+///
+/// println!("{:?}", letter_value('α')); // Some(1)
+/// println!("{:?}", letter_value('Χ')); // Some(600)
+/// println!("{:?}", letter_value('a')); // None
+/// ```
+pub fn letter_value(c: char) -> Option<usize> {
+    [(&HUNDREDS, 100), (&TENS, 10), (&ONES, 1)]
+        .iter()
+        .find_map(|(table, scale)| lookup(table, c).map(|(digit, _)| digit * scale))
+}
+
+struct Parser<'a> {
+    chars: &'a [char],
+    pos: usize,
+    // The case of the first digit letter seen; every following digit letter must match it.
+    case: Option<Case>,
+    // Whether digit letters may differ in case after all.
+    ignore_case: bool,
+    // Whether 6 may be written as `στ`.
+    sigma_tau: bool,
+    // Whether a group needs a keraia when nothing else marks it as a number.
+    require_keraia: bool,
+    // Whether a digit letter of the current group has an overline, which marks the group as a number like a keraia.
+    overlined: bool,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<char> {
+        self.chars.get(self.pos + offset).copied()
+    }
+
+    fn at_end(&self) -> bool {
+        self.pos == self.chars.len()
+    }
+
+    /// Consume the separator between two myriad groups, if any: whitespace, or punctuation with any whitespace around it.
+    fn separator(&mut self) {
+        let skip_whitespace = |parser: &mut Self| {
+            while parser.peek().is_some_and(char::is_whitespace) {
+                parser.pos += 1;
+            }
+        };
+        skip_whitespace(self);
+        if self.peek().is_some_and(|c| GROUP_SEPARATORS.contains(&c)) {
+            self.pos += 1;
+            skip_whitespace(self);
+        }
+    }
+
+    fn error(&self, kind: GreekParseErrorKind) -> GreekParseError {
+        GreekParseError::new(kind, self.pos)
+    }
+
+    /// The error for a character that cannot continue the number, or for the end of the string.
+    fn unexpected(&self) -> GreekParseError {
+        if self.at_end() {
+            self.error(GreekParseErrorKind::UnexpectedEnd)
+        } else {
+            self.error(GreekParseErrorKind::UnexpectedChar)
+        }
+    }
+
+    /// Consume a digit letter from `table` if the next character is one, returning its digit.
+    fn digit(&mut self, table: &[[&str; 2]; 9]) -> Result<Option<usize>, GreekParseError> {
+        let Some((digit, case)) = self.peek().and_then(|c| lookup(table, c)) else {
+            return Ok(None);
+        };
+        if *self.case.get_or_insert(case) != case && !self.ignore_case {
+            return Err(self.error(GreekParseErrorKind::UnexpectedChar));
+        }
+        self.pos += 1;
+        if self.peek() == Some(OVERLINE) {
+            self.pos += 1;
+            self.overlined = true;
+        }
+        Ok(Some(digit))
+    }
+
+    /// The length and case of a sigma-tau `στ` for 6 at the current position, if 6 may be written so.
+    fn sigma_tau_ahead(&self) -> Option<(usize, Case)> {
+        if !self.sigma_tau {
+            return None;
+        }
+        let overline = (self.peek_at(1) == Some(OVERLINE)) as usize;
+        let case = match (canonical(self.peek()?), self.peek_at(1 + overline)?) {
+            ('σ', 'τ') => Case::Lower,
+            ('Σ', 'Τ') => Case::Upper,
+            _ => return None,
+        };
+        Some((2 + overline, case))
+    }
+
+    /// Consume a ones digit, which may be a sigma-tau `στ` for 6.
+    fn one(&mut self) -> Result<Option<usize>, GreekParseError> {
+        let Some((len, case)) = self.sigma_tau_ahead() else {
+            return self.digit(&ONES);
+        };
+        if *self.case.get_or_insert(case) != case && !self.ignore_case {
+            return Err(self.error(GreekParseErrorKind::UnexpectedChar));
+        }
+        self.overlined |= len == 3;
+        self.pos += len;
+        if self.peek() == Some(OVERLINE) {
+            self.pos += 1;
+            self.overlined = true;
+        }
+        Ok(Some(6))
+    }
+
+    /// Consume the myriad prefix (a single digit, lowercase unless `any_case`, followed by `Μ`), returning the myriad
+    /// power.
+    fn myriad_prefix(&mut self, any_case: bool) -> Option<usize> {
+        if let Some((2, Case::Lower)) = self.sigma_tau_ahead() {
+            if self.peek_at(2) == Some(MYRIAD) {
+                self.pos += 3;
+                return Some(6);
+            }
+        }
+        if self.peek_at(1) != Some(MYRIAD) {
+            return None;
+        }
+        match lookup(&ONES, self.peek()?) {
+            Some((power, case)) if case == Case::Lower || any_case => {
+                self.pos += 2;
+                Some(power)
+            }
+            _ => None,
+        }
+    }
+
+    /// Whether a lowercase myriad prefix comes next, without consuming it.
+    fn prefix_ahead(&mut self) -> bool {
+        let pos = self.pos;
+        let prefix = self.myriad_prefix(false).is_some();
+        self.pos = pos;
+        prefix
+    }
+
+    /// Consume a thousands sign written before the myriad prefix, as
+    /// [`ThousandsStyle::BeforeGroup`](crate::ThousandsStyle::BeforeGroup) writes it, together with the prefix and the
+    /// thousands digit, returning the myriad power and the digit. Nothing is consumed if they are not all there.
+    fn thousands_before_prefix(&mut self) -> Option<(usize, usize)> {
+        if self.peek() != Some(THOUSANDS_SIGN) {
+            return None;
+        }
+        let (pos, case, overlined) = (self.pos, self.case, self.overlined);
+        self.pos += 1;
+        // An uppercase thousands digit followed by the tens letter `Μ` looks like a prefix, so only a lowercase one is
+        // taken for it here.
+        if let Some(power) = self.myriad_prefix(false) {
+            if let Ok(Some(thousand)) = self.one() {
+                return Some((power, thousand));
+            }
+        }
+        (self.pos, self.case, self.overlined) = (pos, case, overlined);
+        None
+    }
+
+    /// Parse one myriad group, returning its myriad power and its value within the group (1 to 9999).
+    fn group(&mut self) -> Result<(usize, u128), GreekParseError> {
+        self.overlined = false;
+        let mut value = 0;
+        // The place of the last digit letter read, to tell a repeated digit from one out of order.
+        let mut place = 0;
+        let (power, has_thousand) = match self.thousands_before_prefix() {
+            Some((power, thousand)) => {
+                value += thousand * 1000;
+                place = 1000;
+                (power, true)
+            }
+            None => {
+                // At the start of a group a ones letter can only be followed by `Μ` as a prefix, as the tens come
+                // before the ones, so an uppercase prefix is not ambiguous.
+                let power = self.myriad_prefix(self.ignore_case).unwrap_or(0);
+                let has_thousand = self.peek() == Some(THOUSANDS_SIGN);
+                if has_thousand {
+                    self.pos += 1;
+                    value += self.one()?.ok_or_else(|| self.unexpected())? * 1000;
+                    place = 1000;
+                }
+                (power, has_thousand)
+            }
+        };
+        // A sigma-tau is a 6 rather than a 200 followed by a 300.
+        if self.sigma_tau_ahead().is_none() {
+            if let Some(h) = self.digit(&HUNDREDS)? {
+                value += h * 100;
+                place = 100;
+            }
+        }
+        if let Some(t) = self.digit(&TENS)? {
+            value += t * 10;
+            place = 10;
+        }
+        // Without a separator, a lowercase ones letter followed by `Μ` is the myriad prefix of the next group.
+        let ones = if self.prefix_ahead() {
+            None
+        } else {
+            self.one()?
+        };
+        if let Some(o) = ones {
+            value += o;
+            place = 1;
+        }
+        if value == 0 {
+            return Err(self.unexpected());
+        }
+
+        // The keraia is only required when there is no thousands sign or overline marking the group as a number.
+        let has_keraia = self.peek().is_some_and(is_keraia);
+        if has_keraia {
+            self.pos += 1;
+        } else if self.require_keraia && !has_thousand && !self.overlined {
+            return Err(match self.peek().and_then(letter_value) {
+                Some(v) if place_of(v) == place => self.error(GreekParseErrorKind::RepeatedDigit),
+                Some(_) => self.error(GreekParseErrorKind::OutOfOrder),
+                None => self.error(GreekParseErrorKind::MissingKeraia),
+            });
+        }
+        Ok((power, value as u128))
+    }
+
+    /// Parse the whole numeral. It is too large at the first group that takes the total past `usize::MAX`, as groups
+    /// only add to it.
+    fn number(&mut self) -> Result<usize, GreekParseError> {
+        let mut total: u128 = 0;
+        let mut previous_power = None;
+        loop {
+            let start = self.pos;
+            let (power, value) = self.group()?;
+            // Myriad powers must strictly decrease from group to group.
+            if previous_power.is_some_and(|previous| power >= previous) {
+                return Err(GreekParseError::new(GreekParseErrorKind::OutOfOrder, start));
+            }
+            previous_power = Some(power);
+
+            total = 10_000u128
+                .checked_pow(power as u32)
+                .and_then(|scale| scale.checked_mul(value))
+                .and_then(|scaled| total.checked_add(scaled))
+                .filter(|&total| usize::try_from(total).is_ok())
+                .ok_or(GreekParseError::new(GreekParseErrorKind::TooLarge, start))?;
+
+            if self.at_end() {
+                return Ok(total as usize);
+            }
+            // A missing separator is fine; the next group still has to parse.
+            self.separator();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+    use alloc::vec::Vec;
+
+    use super::{
+        detect_case, from_greek, from_greek_ignore_case, from_greek_lenient, from_greek_with,
+        greek_cmp, greek_to_usize, is_valid_greek_number, letter_value, parse_all, GreekParseError,
+        GreekParseErrorKind,
+    };
+    use crate::{
+        to_greek_lowercase, to_greek_uppercase, Case, GreekNumberFormatter, KeraiaStyle, SixStyle,
+        ZeroStyle,
+    };
+    use proptest::prelude::*;
+
+    macro_rules! from_greek_tests {
+        ($($test_name:ident: $value:expr,)*) => {
+            #[test]
+            fn greek_number_parse_test() {
+                $(
+                    {
+                        let (string, number): (&str, Option<usize>) = $value;
+                        assert_eq!(from_greek(string).ok(), number, stringify!($test_name));
+                    }
+                )*
+            }
+        }
+    }
+
+    from_greek_tests! {
+        single_digit_1_lower: ("αʹ", Some(1)),
+        single_digit_1_upper: ("Αʹ", Some(1)),
+        single_digit_1_modifier_prime: ("α\u{02B9}", Some(1)),
+
+        three_digit_241_lower: ("σμαʹ", Some(241)),
+        three_digit_241_upper: ("ΣΜΑʹ", Some(241)),
+
+        four_digit_5683_lower: ("͵εχπγ", Some(5683)),
+        four_digit_1005_lower: ("͵αε", Some(1005)),
+
+        koppa_90_lower: ("ϟʹ", Some(90)),
+        koppa_90_upper: ("Ϟʹ", Some(90)),
+        archaic_koppa_90_lower: ("ϙʹ", Some(90)),
+        archaic_koppa_90_upper: ("Ϙʹ", Some(90)),
+
+        long_complex_0: ("αΜθʹ, ͵ζφνδ", Some(97_554)),
+        long_complex_0_upper: ("αΜΘʹ, ͵ΖΦΝΔ", Some(97_554)),
+        long_complex_1: ("βΜκʹ, αΜ͵εχπγ, ͵θρπδ", Some(2_056_839_184)),
+
+        trailing_high_digit_0: ("βΜκʹ", Some(2_000_000_000)),
+        trailing_high_digit_1: ("αΜ͵θ, αʹ", Some(90_000_001)),
+
+        empty: ("", None),
+        missing_keraia: ("σμα", None),
+        double_keraia: ("σμαʹʹ", None),
+        out_of_order: ("ασʹ", None),
+        repeated_digit: ("ααʹ", None),
+        lone_thousands_sign: ("͵", None),
+        lone_keraia: ("ʹ", None),
+        mixed_case: ("Σμαʹ", None),
+        myriad_power_not_decreasing: ("αΜαʹ, βΜαʹ", None),
+        separator_comma: ("αΜθʹ,͵ζφνδ", Some(97_554)),
+        separator_middle_dot: ("βΜκʹ\u{00B7}αΜ͵εχπγ\u{00B7}͵θρπδ", Some(2_056_839_184)),
+        separator_ano_teleia: ("βΜκʹ\u{0387}αΜ͵εχπγ\u{0387}͵θρπδ", Some(2_056_839_184)),
+        separator_space: ("αΜθʹ ͵ζφνδ", Some(97_554)),
+        separator_none: ("βΜκʹαΜ͵εχπγ͵θρπδ", Some(2_056_839_184)),
+        separator_space_before_comma: ("αΜθʹ ,͵ζφνδ", Some(97_554)),
+        separator_spaces_around_comma: ("αΜθʹ  ,\t͵ζφνδ", Some(97_554)),
+        separator_spaces_around_middle_dot: ("αΜθʹ · ͵ζφνδ", Some(97_554)),
+        separator_spaces: ("αΜθʹ \n ͵ζφνδ", Some(97_554)),
+        separator_doubled: ("αΜθʹ, , ͵ζφνδ", None),
+        trailing_separator: ("αʹ, ", None),
+        not_greek: ("241", None),
+
+        final_sigma_200: ("ςʹ", Some(200)),
+        final_sigma_241: ("ςμαʹ", Some(241)),
+        final_sigma_thousands: ("͵βςμα", Some(2241)),
+        final_sigma_myriad: ("αΜςʹ, ͵ζφνδ", Some(2_007_554)),
+
+        surrounding_whitespace: ("  σμαʹ \n", Some(241)),
+        stray_overline: ("  σμαʹ \u{0305}", Some(241)),
+        overlined_single: ("α\u{0305}", Some(1)),
+        overlined: ("σ\u{0305}μ\u{0305}α\u{0305}", Some(241)),
+        overlined_myriads: ("αΜθ\u{0305}, ͵ζ\u{0305}φ\u{0305}ν\u{0305}δ\u{0305}", Some(97_554)),
+        interior_whitespace: ("σ μαʹ", None),
+        interior_junk: ("σμ-αʹ", None),
+        interior_stray_overline: ("σμαʹ\u{0305}, αʹ", None),
+        zero_sign_with_whitespace: (" 𐆊 ", Some(0)),
+
+        zero_sign: ("𐆊", Some(0)),
+        zero_sign_with_keraia: ("𐆊ʹ", Some(0)),
+        zero_sign_with_modifier_prime: ("𐆊\u{02B9}", Some(0)),
+        zero_sign_with_two_keraias: ("𐆊ʹʹ", None),
+    }
+
+    #[test]
+    fn is_valid_greek_number_test() {
+        for valid in ["αʹ", "σμαʹ", "͵εχπγ", "αΜθʹ, ͵ζφνδ", "𐆊"] {
+            assert!(is_valid_greek_number(valid), "{}", valid);
+        }
+        for invalid in ["", "σμαʹʹ", "ασʹ", "͵", "͵ʹ", "αΜ", "σμα", "αʹ, αʹ"] {
+            assert!(!is_valid_greek_number(invalid), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn greek_number_round_trip_test() {
+        for n in (0..=100_000).chain([usize::MAX, usize::MAX - 1]) {
+            assert_eq!(from_greek(&to_greek_lowercase(n)), Ok(n), "{}", n);
+            assert_eq!(from_greek(&to_greek_uppercase(n)), Ok(n), "{}", n);
+        }
+        for n in (0..usize::BITS).map(|shift| 1usize << shift) {
+            assert_eq!(from_greek(&to_greek_lowercase(n)), Ok(n), "{}", n);
+            assert_eq!(from_greek(&to_greek_uppercase(n)), Ok(n), "{}", n);
+        }
+    }
+
+    #[test]
+    fn parse_error_position_test() {
+        use GreekParseErrorKind::*;
+
+        let cases = [
+            ("σασʹ", OutOfOrder, 2),
+            ("ασʹ", OutOfOrder, 1),
+            ("ααʹ", RepeatedDigit, 1),
+            ("ρρʹ", RepeatedDigit, 1),
+            ("σμμʹ", RepeatedDigit, 2),
+            ("μσʹ", OutOfOrder, 1),
+            ("αιʹ", OutOfOrder, 1),
+            ("", Empty, 0),
+            ("σμα", MissingKeraia, 3),
+            ("σμα, αʹ", MissingKeraia, 3),
+            ("σμαʹʹ", UnexpectedChar, 4),
+            ("Σμαʹ", UnexpectedChar, 1),
+            ("241", UnexpectedChar, 0),
+            ("͵", UnexpectedEnd, 1),
+            ("αʹ, ", UnexpectedEnd, 3),
+            ("  σμα ", MissingKeraia, 5),
+            ("  ", Empty, 2),
+            ("σμ xαʹ", MissingKeraia, 2),
+            ("αΜαʹ, βΜαʹ", OutOfOrder, 6),
+            ("𐆊ʹʹ", UnexpectedChar, 1),
+        ];
+        for (string, kind, char_index) in cases {
+            let error = from_greek(string).unwrap_err();
+            assert_eq!(
+                (error.kind(), error.char_index()),
+                (kind, char_index),
+                "{}",
+                string
+            );
+        }
+        #[cfg(target_pointer_width = "64")]
+        {
+            assert_eq!(from_greek("εΜαʹ").unwrap_err().kind(), TooLarge);
+            let error = from_greek("  εΜαʹ").unwrap_err();
+            assert_eq!((error.kind(), error.char_index()), (TooLarge, 2));
+            // the group that takes the total past `usize::MAX`, not the first one
+            let error = from_greek("δΜ͵αωμδ, γΜ͵θ").unwrap_err();
+            assert_eq!((error.kind(), error.char_index()), (TooLarge, 9));
+        }
+    }
+
+    #[test]
+    fn parse_error_display_test() {
+        let error: GreekParseError = from_greek("σασʹ").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "digit or myriad group out of order at character 2"
+        );
+    }
+
+    #[test]
+    fn from_greek_ignore_case_test() {
+        assert_eq!(from_greek_ignore_case("σμαʹ"), Ok(241));
+        assert_eq!(from_greek_ignore_case("Σμαʹ"), Ok(241));
+        assert_eq!(from_greek_ignore_case("σΜαʹ"), Ok(241));
+        assert_eq!(from_greek_ignore_case("αΜΘʹ, ͵ζφνδ"), Ok(97_554));
+        assert_eq!(from_greek_ignore_case("ΑΜΘʹ, ͵ΖΦΝΔ"), Ok(97_554));
+        assert_eq!(
+            from_greek_ignore_case("ΒΜκʹ, ΑΜ͵Εχπγ, ͵Θρπδ"),
+            Ok(2_056_839_184)
+        );
+        assert_eq!(from_greek_ignore_case("ΜΑʹ"), Ok(41));
+        // not a thousands sign before a myriad prefix, as no thousands digit follows
+        assert_eq!(from_greek_ignore_case("͵ΑΜʹ"), Ok(1040));
+        assert_eq!(from_greek_ignore_case("͵ΑΜΕ"), Ok(1045));
+        assert_eq!(from_greek_ignore_case("͵ΑΜΑ"), Ok(1041));
+        // a thousands sign is only read before a lowercase prefix
+        assert_eq!(from_greek_ignore_case("͵αΜΕχπγ"), Ok(56_830_000));
+        for n in 0..100_000 {
+            assert_eq!(
+                from_greek_ignore_case(&to_greek_uppercase(n)),
+                Ok(n),
+                "{}",
+                n
+            );
+        }
+        assert!(from_greek("Σμαʹ").is_err());
+        assert!(from_greek_ignore_case("ασʹ").is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn round_trip_property_test(n in prop_oneof![
+            any::<usize>(),
+            0..100_000usize,
+            Just(usize::MAX),
+            (0..usize::BITS).prop_map(|shift| 1usize << shift),
+            (0..5u32).prop_map(|power| 10_000usize.pow(power)),
+        ]) {
+            prop_assert_eq!(from_greek(&to_greek_lowercase(n)), Ok(n));
+            prop_assert_eq!(from_greek(&to_greek_uppercase(n)), Ok(n));
+        }
+
+        #[test]
+        fn round_trip_formatter_property_test(
+            n in any::<usize>(),
+            keraia in prop_oneof![
+                Just(KeraiaStyle::NumeralSign),
+                Just(KeraiaStyle::ModifierPrime),
+                Just(KeraiaStyle::Overline),
+            ],
+            separator in prop_oneof![Just(", "), Just(","), Just("\u{00B7}"), Just(" ")],
+        ) {
+            let formatter = GreekNumberFormatter::new().keraia(keraia).separator(separator);
+            prop_assert_eq!(from_greek(&formatter.format(n)), Ok(n));
+        }
+    }
+
+    #[test]
+    fn detect_case_test() {
+        assert_eq!(detect_case("σμαʹ"), Some(Case::Lower));
+        assert_eq!(detect_case("ΣΜΑʹ"), Some(Case::Upper));
+        assert_eq!(detect_case("Μʹ"), Some(Case::Upper));
+        assert_eq!(detect_case("αΜθʹ, ͵ζφνδ"), Some(Case::Lower));
+        assert_eq!(detect_case("αΜΘʹ, ͵ΖΦΝΔ"), Some(Case::Upper));
+        assert_eq!(detect_case("αΜΜʹ"), Some(Case::Upper));
+        assert_eq!(detect_case("Σμαʹ"), None);
+        assert_eq!(detect_case("αΜΘʹ, ͵ζφνδ"), None);
+        assert_eq!(detect_case("𐆊"), None);
+        assert_eq!(detect_case("͵ʹ"), None);
+        for n in [1, 241, 97_554, 2_056_839_184] {
+            assert_eq!(detect_case(&to_greek_lowercase(n)), Some(Case::Lower));
+            assert_eq!(detect_case(&to_greek_uppercase(n)), Some(Case::Upper));
+        }
+    }
+
+    #[test]
+    fn from_greek_with_sigma_tau_test() {
+        let sigma_tau = |s: &str| from_greek_with(SixStyle::SigmaTau, s);
+        assert_eq!(sigma_tau("στʹ"), Ok(6));
+        assert_eq!(sigma_tau("ΣΤʹ"), Ok(6));
+        assert_eq!(sigma_tau("σʹ"), Ok(200));
+        assert_eq!(sigma_tau("τʹ"), Ok(300));
+        // a 200 followed by a sigma-tau 6
+        assert_eq!(sigma_tau("σστʹ"), Ok(206));
+        assert_eq!(sigma_tau("σοστʹ"), Ok(276));
+        assert_eq!(sigma_tau("͵στ"), Ok(6000));
+        assert_eq!(sigma_tau("͵στχξστ"), Ok(6666));
+        // a sixth-myriad prefix is too large for a usize, but still read as one
+        assert_eq!(
+            sigma_tau("στΜαʹ").map_err(|e| e.kind()),
+            Err(GreekParseErrorKind::TooLarge)
+        );
+        assert_eq!(sigma_tau("σ\u{0305}τ\u{0305}"), Ok(6));
+        assert_eq!(sigma_tau("ϛʹ"), Ok(6));
+        assert!(sigma_tau("Στʹ").is_err());
+        assert!(sigma_tau("στστʹ").is_err());
+        assert!(from_greek("στʹ").is_err());
+        assert_eq!(from_greek_with(SixStyle::Stigma, "σμαʹ"), Ok(241));
+
+        let formatter = GreekNumberFormatter::new().six(SixStyle::SigmaTau);
+        for n in [
+            6,
+            66,
+            606,
+            666,
+            6666,
+            60_001,
+            60_006,
+            2_056_839_184,
+            usize::MAX,
+        ] {
+            assert_eq!(sigma_tau(&formatter.format(n)), Ok(n), "{}", n);
+            assert_eq!(
+                sigma_tau(&formatter.clone().keraia(KeraiaStyle::Overline).format(n)),
+                Ok(n),
+                "{}",
+                n
+            );
+        }
+    }
+
+    #[test]
+    fn thousands_round_trip_test() {
+        assert_eq!(from_greek("͵ϛ"), Ok(6000));
+        assert_eq!(from_greek("͵Ϛ"), Ok(6000));
+        assert_eq!(from_greek("͵ϝ"), Ok(6000));
+        assert_eq!(from_greek_with(SixStyle::SigmaTau, "͵στ"), Ok(6000));
+        assert_eq!(from_greek_with(SixStyle::SigmaTau, "͵ΣΤ"), Ok(6000));
+
+        for six in [SixStyle::Stigma, SixStyle::SigmaTau, SixStyle::Digamma] {
+            for case in [Case::Lower, Case::Upper] {
+                let formatter = GreekNumberFormatter::new().six(six).case(case);
+                for n in (1000..=9000).step_by(1000) {
+                    let greek = formatter.format(n);
+                    assert_eq!(greek.chars().next(), Some('͵'), "{}", greek);
+                    assert_eq!(from_greek_with(six, &greek), Ok(n), "{}", greek);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn letter_value_test() {
+        assert_eq!(letter_value('α'), Some(1));
+        assert_eq!(letter_value('Α'), Some(1));
+        assert_eq!(letter_value('ϛ'), Some(6));
+        assert_eq!(letter_value('ϝ'), Some(6));
+        assert_eq!(letter_value('θ'), Some(9));
+        assert_eq!(letter_value('ι'), Some(10));
+        assert_eq!(letter_value('Μ'), Some(40));
+        assert_eq!(letter_value('ϟ'), Some(90));
+        assert_eq!(letter_value('ϙ'), Some(90));
+        assert_eq!(letter_value('ρ'), Some(100));
+        assert_eq!(letter_value('ς'), Some(200));
+        assert_eq!(letter_value('χ'), Some(600));
+        assert_eq!(letter_value('ϡ'), Some(900));
+        assert_eq!(letter_value('Ͳ'), Some(900));
+        assert_eq!(letter_value('a'), None);
+        assert_eq!(letter_value('ʹ'), None);
+        assert_eq!(letter_value('͵'), None);
+        assert_eq!(letter_value('𐆊'), None);
+    }
+
+    #[test]
+    fn greek_cmp_test() {
+        use core::cmp::Ordering::*;
+
+        assert_eq!(greek_cmp("αʹ", "σμαʹ"), Some(Less));
+        assert_eq!(greek_cmp("σμαʹ", "αʹ"), Some(Greater));
+        // `ω` sorts after `α` as a string, but 800 is less than 1000
+        assert_eq!(greek_cmp("ωʹ", "͵α"), Some(Less));
+        assert_eq!(greek_cmp("σμαʹ", "ΣΜΑʹ"), Some(Equal));
+        assert_eq!(greek_cmp("𐆊", "αʹ"), Some(Less));
+        assert_eq!(greek_cmp("σμα", "αʹ"), None);
+        assert_eq!(greek_cmp("αʹ", ""), None);
+    }
+
+    #[test]
+    fn parse_all_test() {
+        fn values(text: &str) -> Vec<(&str, usize)> {
+            parse_all(text)
+                .into_iter()
+                .map(|(range, value)| (&text[range], value))
+                .collect()
+        }
+        assert_eq!(
+            values("Κεφάλαιον σμαʹ, στίχος αΜθʹ, ͵ζφνδ."),
+            [("σμαʹ", 241), ("αΜθʹ, ͵ζφνδ", 97_554)]
+        );
+        assert_eq!(values("βιβλίον αʹ και βʹ"), [("αʹ", 1), ("βʹ", 2)]);
+        // two numbers of the same myriad power are two numerals
+        assert_eq!(values("αʹ, βʹ"), [("αʹ", 1), ("βʹ", 2)]);
+        assert_eq!(values("͵α ἔτη"), [("͵α", 1000)]);
+        assert_eq!(values("𐆊"), [("𐆊", 0)]);
+        // digit letters inside or at the start of words are not numerals
+        assert_eq!(values("και λόγος σμαʹx ασμαʹ"), []);
+        assert_eq!(values(""), []);
+
+        let text = "τὸ σμαʹ";
+        assert_eq!(parse_all(text), [(6..14, 241)]);
+    }
+
+    #[test]
+    fn greek_to_usize_test() {
+        for s in ["𐆊", "αʹ", "σμαʹ", "ΣΜΑʹ", "αΜθʹ, ͵ζφνδ"] {
+            assert_eq!(greek_to_usize(s), from_greek(s));
+            assert!(greek_to_usize(s).is_ok());
+        }
+        assert_eq!(greek_to_usize("σμαʹ"), Ok(241));
+        for s in ["", "σμα", "ασʹ", "241"] {
+            assert!(greek_to_usize(s).is_err(), "{}", s);
+        }
+    }
+
+    #[test]
+    fn from_greek_lenient_test() {
+        let without = GreekNumberFormatter::new().keraia(KeraiaStyle::None);
+        for n in [1, 241, 5683, 97_554, 90_000_001, 2_056_839_184] {
+            let greek = without.format(n);
+            assert_eq!(from_greek_lenient(&greek), Ok(n), "{}", greek);
+            assert_eq!(from_greek_lenient(&to_greek_lowercase(n)), Ok(n));
+        }
+        assert_eq!(
+            from_greek("σμα").unwrap_err().kind(),
+            GreekParseErrorKind::MissingKeraia
+        );
+        assert_eq!(from_greek_lenient("σμα"), Ok(241));
+        assert_eq!(from_greek_lenient("ΣΜΑ"), Ok(241));
+        for s in ["", "ασ", "σμαx", "σμαʹʹ"] {
+            assert!(from_greek_lenient(s).is_err(), "{}", s);
+        }
+    }
+
+    #[test]
+    fn zero_styles_round_trip_test() {
+        for zero in [ZeroStyle::Sign, ZeroStyle::Omicron, ZeroStyle::Digit] {
+            for zero_keraia in [false, true] {
+                for keraia in [
+                    KeraiaStyle::NumeralSign,
+                    KeraiaStyle::ModifierPrime,
+                    KeraiaStyle::Overline,
+                ] {
+                    for case in [Case::Lower, Case::Upper] {
+                        let formatter = GreekNumberFormatter::new()
+                            .zero(zero)
+                            .zero_keraia(zero_keraia)
+                            .keraia(keraia)
+                            .case(case);
+                        let greek = formatter.format(0);
+                        assert_eq!(from_greek(&greek).ok(), Some(0), "{}", greek);
+                    }
+                }
+            }
+        }
+        assert_eq!(from_greek("𐆊"), Ok(0));
+        assert_eq!(from_greek("0"), Ok(0));
+        assert_eq!(from_greek(" 0 "), Ok(0));
+        assert_eq!(detect_case("0"), None);
+        assert_eq!(from_greek(&to_greek_lowercase(0)), Ok(0));
+        assert_eq!(from_greek(" ο\u{0304} "), Ok(0));
+        assert_eq!(detect_case("Ο\u{0304}"), Some(Case::Upper));
+        // A bare omicron is still 70, and nothing but a keraia may follow a zero.
+        assert_eq!(from_greek("οʹ"), Ok(70));
+        for s in ["ο\u{0304}ʹʹ", "00", "0ʹα", "𐆊α", "1", "10", "0α"] {
+            assert!(from_greek(s).is_err(), "{}", s);
+        }
+    }
+}
@@ -0,0 +1,119 @@
+use alloc::string::String;
+
+use crate::{to_greek, Case};
+
+// The Roman signs and their subtractive pairs, from the largest value down.
+const ROMAN: [(&str, usize); 13] = [
+    ("M", 1000),
+    ("CM", 900),
+    ("D", 500),
+    ("CD", 400),
+    ("C", 100),
+    ("XC", 90),
+    ("L", 50),
+    ("XL", 40),
+    ("X", 10),
+    ("IX", 9),
+    ("V", 5),
+    ("IV", 4),
+    ("I", 1),
+];
+
+/// The standard uppercase Roman numeral of `n`.
+fn to_roman(mut n: usize) -> String {
+    let mut roman = String::new();
+    for (sign, value) in ROMAN {
+        while n >= value {
+            roman.push_str(sign);
+            n -= value;
+        }
+    }
+    roman
+}
+
+/// Read a Roman numeral, if it is written in the standard subtractive form.
+fn from_roman(roman: &str) -> Option<usize> {
+    let roman = roman.to_ascii_uppercase();
+    let mut rest = roman.as_str();
+    let mut n = 0;
+    for (sign, value) in ROMAN {
+        while let Some(after) = rest.strip_prefix(sign) {
+            rest = after;
+            n += value;
+        }
+    }
+    // Only the standard form writes the number back the same way, which rules out e.g. `IIII` and `IC`.
+    (rest.is_empty() && n > 0 && to_roman(n) == roman).then_some(n)
+}
+
+/// Convert a Roman numeral to Greek numbers
+///
+/// The Roman numeral may be in either case and must be in the standard subtractive form, e.g. `XLIX` for 49 but not
+/// `IL`; thousands are written with as many `M` as needed. Returns `None` for anything else, including the empty
+/// string.
+///
+/// # Examples
+///
+/// ```
+/// use greek_number::{roman_to_greek, Case};
+///
+/// let greek = roman_to_greek("MMXXIV", Case::Lower);
+/// println!("{:?}", greek); // Some("͵βκδ")
+///
+/// let greek = roman_to_greek("IIII", Case::Lower);
+/// println!("{:?}", greek); // None
+/// ```
+pub fn roman_to_greek(roman: &str, case: Case) -> Option<String> {
+    from_roman(roman).map(|n| to_greek(n, case))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{from_roman, roman_to_greek, to_roman};
+    use crate::{to_greek_lowercase, to_greek_uppercase, Case};
+
+    #[test]
+    fn roman_to_greek_test() {
+        assert_eq!(
+            roman_to_greek("MMXXIV", Case::Lower),
+            Some(to_greek_lowercase(2024))
+        );
+        assert_eq!(
+            roman_to_greek("mmxxiv", Case::Upper),
+            Some(to_greek_uppercase(2024))
+        );
+        assert_eq!(
+            roman_to_greek("CCXLI", Case::Lower).as_deref(),
+            Some("σμαʹ")
+        );
+        assert_eq!(roman_to_greek("", Case::Lower), None);
+        assert_eq!(roman_to_greek("MMXXIV ", Case::Lower), None);
+    }
+
+    #[test]
+    fn from_roman_test() {
+        let cases = [
+            ("I", Some(1)),
+            ("IV", Some(4)),
+            ("IX", Some(9)),
+            ("XLIX", Some(49)),
+            ("XCIX", Some(99)),
+            ("CDXLIV", Some(444)),
+            ("MCMLXXXII", Some(1982)),
+            ("MMMCMXCIX", Some(3999)),
+            ("MMMM", Some(4000)),
+            ("IIII", None),
+            ("IL", None),
+            ("VX", None),
+            ("IIV", None),
+            ("XIIIIX", None),
+            ("ABC", None),
+        ];
+        for (roman, n) in cases {
+            assert_eq!(from_roman(roman), n, "{}", roman);
+        }
+        for n in 1..5000 {
+            assert_eq!(from_roman(&to_roman(n)), Some(n));
+        }
+    }
+}
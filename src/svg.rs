@@ -0,0 +1,145 @@
+use alloc::string::String;
+use core::fmt::Write;
+
+use crate::{Case, GreekNumberFormatter, KeraiaStyle};
+
+/// Where and how [`to_greek_svg`] draws a numeral
+///
+/// Start from [`SvgOptions::new`]: a 16 unit font with the baseline at `(0, 16)`, marked by a keraia.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SvgOptions {
+    x: i32,
+    y: i32,
+    font_size: i32,
+    overline: bool,
+}
+
+impl Default for SvgOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SvgOptions {
+    /// Create options with a 16 unit font, the baseline at `(0, 16)` and a keraia rather than an overline.
+    pub fn new() -> Self {
+        SvgOptions {
+            x: 0,
+            y: 16,
+            font_size: 16,
+            overline: false,
+        }
+    }
+
+    /// Set the start of the baseline of the text.
+    pub fn position(mut self, x: i32, y: i32) -> Self {
+        self.x = x;
+        self.y = y;
+        self
+    }
+
+    /// Set the font size, in user units.
+    pub fn font_size(mut self, font_size: i32) -> Self {
+        self.font_size = font_size;
+        self
+    }
+
+    /// Set whether the numeral is marked by a `<line>` drawn over it, as in manuscripts, instead of the keraia.
+    pub fn overline(mut self, overline: bool) -> Self {
+        self.overline = overline;
+        self
+    }
+}
+
+/// Stringify a number to Greek numbers as an SVG element
+///
+/// Without an overline this is a single `<text>` element. With one, the numeral is written without keraia and the
+/// `<text>` is grouped with a `<line>` above it. SVG does not tell how wide the text will be, so the text is given a
+/// `textLength` of 0.6 em per character and stretched to it, and the line spans exactly that length.
+///
+/// # Examples
+///
+/// ```
+/// use greek_number::{to_greek_svg, Case, SvgOptions};
+///
+/// let svg = to_greek_svg(241, Case::Lower, SvgOptions::new());
+/// println!("{}", svg); // <text x="0" y="16" font-size="16">σμαʹ</text>
+///
+/// let svg = to_greek_svg(241, Case::Lower, SvgOptions::new().overline(true));
+/// println!("{}", svg);
+/// // <g><text x="0" y="16" font-size="16" textLength="28" lengthAdjust="spacingAndGlyphs">σμα</text>
+/// // <line x1="0" y1="4" x2="28" y2="4" stroke="currentColor" stroke-width="1"/></g>
+/// ```
+pub fn to_greek_svg(n: usize, case: Case, opts: SvgOptions) -> String {
+    let SvgOptions {
+        x,
+        y,
+        font_size,
+        overline,
+    } = opts;
+    let mut formatter = GreekNumberFormatter::new().case(case);
+    if overline {
+        formatter = formatter.keraia(KeraiaStyle::None);
+    }
+    let numeral = formatter.format(n);
+
+    let mut svg = String::new();
+    if !overline {
+        write!(
+            svg,
+            r#"<text x="{}" y="{}" font-size="{}">{}</text>"#,
+            x, y, font_size, numeral
+        )
+        .expect("writing to a String cannot fail");
+        return svg;
+    }
+    let width = numeral.chars().count() as i32 * font_size * 3 / 5;
+    // a little above the top of the letters
+    let line_y = y - font_size * 4 / 5;
+    let stroke = (font_size / 16).max(1);
+    write!(
+        svg,
+        r#"<g><text x="{}" y="{}" font-size="{}" textLength="{}" lengthAdjust="spacingAndGlyphs">{}</text>"#,
+        x, y, font_size, width, numeral
+    )
+    .expect("writing to a String cannot fail");
+    write!(
+        svg,
+        r#"<line x1="{}" y1="{}" x2="{}" y2="{}" stroke="currentColor" stroke-width="{}"/></g>"#,
+        x,
+        line_y,
+        x + width,
+        line_y,
+        stroke
+    )
+    .expect("writing to a String cannot fail");
+    svg
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{to_greek_svg, SvgOptions};
+    use crate::Case;
+
+    #[test]
+    fn to_greek_svg_test() {
+        let svg = to_greek_svg(241, Case::Upper, SvgOptions::new());
+        assert_eq!(svg, r#"<text x="0" y="16" font-size="16">ΣΜΑʹ</text>"#);
+        assert!(!svg.contains("<line"));
+
+        let options = SvgOptions::new()
+            .position(10, 50)
+            .font_size(40)
+            .overline(true);
+        let svg = to_greek_svg(241, Case::Lower, options);
+        assert!(svg.contains(">σμα</text>"));
+        assert!(svg.contains(r#"textLength="72""#));
+        assert!(svg.contains(
+            r#"<line x1="10" y1="18" x2="82" y2="18" stroke="currentColor" stroke-width="2"/>"#
+        ));
+        assert!(svg.starts_with("<g>") && svg.ends_with("</g>"));
+
+        let svg = to_greek_svg(0, Case::Lower, SvgOptions::new());
+        assert!(svg.contains(">𐆊</text>"));
+    }
+}
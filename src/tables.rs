@@ -0,0 +1,246 @@
+//! The digit glyphs the crate writes
+//!
+//! Each table is indexed by `[digit - 1][case]`, where case `0` is lowercase and `1` is uppercase. These are the
+//! defaults [`GreekNumberFormatter`](crate::GreekNumberFormatter) uses: 6 is the stigma `ϛ`/`Ϛ` (see
+//! [`SixStyle`](crate::SixStyle) for the alternatives), 900 is the sampi `ϡ`/`Ϡ` (see
+//! [`SampiStyle`](crate::SampiStyle)) and 90 is the numeral koppa `ϟ`/`Ϟ` (U+03DF/U+03DE) rather than the archaic koppa
+//! `ϙ`/`Ϙ` (U+03D9/U+03D8), so both cases come from the same letter pair. The parser still accepts the archaic forms.
+//!
+//! # Examples
+//!
+//! ```
+//! use greek_number::tables::{HUNDREDS, TENS};
+//!
+//! println!("{}{}", HUNDREDS[1][0], TENS[3][0]); // "σμ"
+//! ```
+
+use crate::Case;
+
+/// The keraia U+0374 GREEK NUMERAL SIGN, written after the letters of a group to mark them as a number.
+pub const KERAIA: char = '\u{0374}';
+
+/// The lower numeral sign that turns a ones letter into a thousand.
+pub const THOUSANDS_SIGN: char = '͵';
+
+/// Glyphs for 1000 to 9000: the ones letters marked with [`THOUSANDS_SIGN`].
+pub const THOUSANDS: [[&str; 2]; 9] = [
+    ["͵α", "͵Α"],
+    ["͵β", "͵Β"],
+    ["͵γ", "͵Γ"],
+    ["͵δ", "͵Δ"],
+    ["͵ε", "͵Ε"],
+    ["͵ϛ", "͵Ϛ"],
+    ["͵ζ", "͵Ζ"],
+    ["͵η", "͵Η"],
+    ["͵θ", "͵Θ"],
+];
+
+/// Glyphs for 100 to 900.
+pub const HUNDREDS: [[&str; 2]; 9] = [
+    ["ρ", "Ρ"],
+    ["σ", "Σ"],
+    ["τ", "Τ"],
+    ["υ", "Υ"],
+    ["φ", "Φ"],
+    ["χ", "Χ"],
+    ["ψ", "Ψ"],
+    ["ω", "Ω"],
+    ["ϡ", "Ϡ"],
+];
+
+/// Glyphs for 10 to 90.
+pub const TENS: [[&str; 2]; 9] = [
+    ["ι", "Ι"],
+    ["κ", "Κ"],
+    ["λ", "Λ"],
+    ["μ", "Μ"],
+    ["ν", "Ν"],
+    ["ξ", "Ξ"],
+    ["ο", "Ο"],
+    ["π", "Π"],
+    ["ϟ", "Ϟ"],
+];
+
+/// Glyphs for 1 to 9.
+pub const ONES: [[&str; 2]; 9] = [
+    ["α", "Α"],
+    ["β", "Β"],
+    ["γ", "Γ"],
+    ["δ", "Δ"],
+    ["ε", "Ε"],
+    ["ϛ", "Ϛ"],
+    ["ζ", "Ζ"],
+    ["η", "Η"],
+    ["θ", "Θ"],
+];
+
+/// Coptic glyphs for 100 to 900, ending with the Coptic sampi `ⳁ`/`Ⳁ`.
+pub const COPTIC_HUNDREDS: [[&str; 2]; 9] = [
+    ["ⲣ", "Ⲣ"],
+    ["ⲥ", "Ⲥ"],
+    ["ⲧ", "Ⲧ"],
+    ["ⲩ", "Ⲩ"],
+    ["ⲫ", "Ⲫ"],
+    ["ⲭ", "Ⲭ"],
+    ["ⲯ", "Ⲯ"],
+    ["ⲱ", "Ⲱ"],
+    ["ⳁ", "Ⳁ"],
+];
+
+/// Coptic glyphs for 10 to 90, with fei `ϥ`/`Ϥ` for 90.
+pub const COPTIC_TENS: [[&str; 2]; 9] = [
+    ["ⲓ", "Ⲓ"],
+    ["ⲕ", "Ⲕ"],
+    ["ⲗ", "Ⲗ"],
+    ["ⲙ", "Ⲙ"],
+    ["ⲛ", "Ⲛ"],
+    ["ⲝ", "Ⲝ"],
+    ["ⲟ", "Ⲟ"],
+    ["ⲡ", "Ⲡ"],
+    ["ϥ", "Ϥ"],
+];
+
+/// Coptic glyphs for 1 to 9, with sou `ⲋ`/`Ⲋ` for 6.
+pub const COPTIC_ONES: [[&str; 2]; 9] = [
+    ["ⲁ", "Ⲁ"],
+    ["ⲃ", "Ⲃ"],
+    ["ⲅ", "Ⲅ"],
+    ["ⲇ", "Ⲇ"],
+    ["ⲉ", "Ⲉ"],
+    ["ⲋ", "Ⲋ"],
+    ["ⲍ", "Ⲍ"],
+    ["ⲏ", "Ⲏ"],
+    ["ⲑ", "Ⲑ"],
+];
+
+/// Every numeral letter as `(value, lowercase, uppercase)`, from 1 up to 900
+///
+/// These are the [`ONES`], [`TENS`] and [`HUNDREDS`] in one list, so they share the choices made there: the stigma for
+/// 6, the numeral koppa for 90 and the sampi for 900.
+///
+/// # Examples
+///
+/// ```
+/// use greek_number::GREEK_NUMERALS;
+///
+/// for (value, lower, upper) in &GREEK_NUMERALS[..3] {
+///     println!("{} {} {}", value, lower, upper);
+/// }
+/// // "1 α Α"
+/// // "2 β Β"
+/// // "3 γ Γ"
+/// ```
+pub const GREEK_NUMERALS: [(usize, &str, &str); 27] = {
+    let tables = [(&ONES, 1), (&TENS, 10), (&HUNDREDS, 100)];
+    let mut numerals = [(0, "", ""); 27];
+    let mut i = 0;
+    while i < numerals.len() {
+        let (table, scale) = tables[i / 9];
+        let [lower, upper] = table[i % 9];
+        numerals[i] = ((i % 9 + 1) * scale, lower, upper);
+        i += 1;
+    }
+    numerals
+};
+
+/// The ones glyph of a single decimal digit, usable in constants
+///
+/// 0 has no letter in the ones position, so it gives the empty string; numbers that are zero as a whole are written
+/// with the zero sign `𐆊` instead.
+///
+/// # Panics
+///
+/// Panics if `digit` is above 9, which is a compile error in a constant.
+///
+/// # Examples
+///
+/// ```
+/// use greek_number::{greek_digit, Case};
+///
+/// const SIX: &str = greek_digit(6, Case::Upper);
+/// println!("{}", SIX); // "Ϛ"
+/// ```
+pub const fn greek_digit(digit: u8, case: Case) -> &'static str {
+    assert!(digit <= 9, "a decimal digit is at most 9");
+    if digit == 0 {
+        return "";
+    }
+    let case = match case {
+        Case::Lower => 0,
+        Case::Upper => 1,
+    };
+    ONES[digit as usize - 1][case]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        greek_digit, COPTIC_HUNDREDS, COPTIC_ONES, COPTIC_TENS, GREEK_NUMERALS, ONES, THOUSANDS,
+        THOUSANDS_SIGN,
+    };
+    use crate::Case;
+
+    #[test]
+    fn thousands_are_marked_ones_test() {
+        for (thousand, one) in THOUSANDS.iter().zip(ONES) {
+            for case in 0..2 {
+                assert_eq!(thousand[case].strip_prefix(THOUSANDS_SIGN), Some(one[case]));
+            }
+        }
+    }
+
+    #[test]
+    fn coptic_tables_test() {
+        for table in [COPTIC_ONES, COPTIC_TENS, COPTIC_HUNDREDS] {
+            for [lower, upper] in table {
+                assert_eq!(lower.to_uppercase(), upper);
+                assert_eq!(upper.to_lowercase(), lower);
+            }
+        }
+    }
+
+    #[test]
+    fn greek_digit_test() {
+        const LOWER: [&str; 10] = [
+            greek_digit(0, Case::Lower),
+            greek_digit(1, Case::Lower),
+            greek_digit(2, Case::Lower),
+            greek_digit(3, Case::Lower),
+            greek_digit(4, Case::Lower),
+            greek_digit(5, Case::Lower),
+            greek_digit(6, Case::Lower),
+            greek_digit(7, Case::Lower),
+            greek_digit(8, Case::Lower),
+            greek_digit(9, Case::Lower),
+        ];
+        assert_eq!(LOWER, ["", "α", "β", "γ", "δ", "ε", "ϛ", "ζ", "η", "θ"]);
+        for digit in 1..=9 {
+            assert_eq!(greek_digit(digit, Case::Upper), ONES[digit as usize - 1][1]);
+        }
+        assert_eq!(greek_digit(0, Case::Upper), "");
+    }
+
+    #[test]
+    #[should_panic]
+    fn greek_digit_above_nine_test() {
+        greek_digit(10, Case::Lower);
+    }
+
+    #[test]
+    fn greek_numerals_test() {
+        assert_eq!(GREEK_NUMERALS.len(), 27);
+        assert_eq!(GREEK_NUMERALS[0], (1, "α", "Α"));
+        assert_eq!(GREEK_NUMERALS[5], (6, "ϛ", "Ϛ"));
+        assert_eq!(GREEK_NUMERALS[17], (90, "ϟ", "Ϟ"));
+        assert_eq!(GREEK_NUMERALS[19], (200, "σ", "Σ"));
+        assert_eq!(GREEK_NUMERALS[26], (900, "ϡ", "Ϡ"));
+        for (value, lower, upper) in GREEK_NUMERALS {
+            for glyph in [lower, upper] {
+                assert_eq!(
+                    crate::letter_value(glyph.chars().next().unwrap()),
+                    Some(value)
+                );
+            }
+        }
+    }
+}